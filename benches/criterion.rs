@@ -32,9 +32,11 @@
 //! ```
 #![allow(missing_docs)]
 use self::vrd::random::Random;
+use self::vrd::samplers::BernoulliSampler;
 use criterion::{
     black_box, criterion_group, criterion_main, Criterion,
 };
+use rand::RngCore;
 use vrd;
 
 /// Benchmarks the random number generation functions provided by the `Random` trait.
@@ -108,6 +110,39 @@ fn benchmark_random(c: &mut Criterion) {
         let mut rng = Random::new();
         b.iter(|| rng.range(black_box(0), black_box(100)))
     });
+
+    // Benchmark repeated bool calls with a fixed probability
+    c.bench_function("Random bool fixed probability", |b| {
+        let mut rng = Random::new();
+        b.iter(|| rng.bool(black_box(0.3)))
+    });
+
+    // Benchmark the precomputed Bernoulli sampler
+    c.bench_function("Bernoulli sampler", |b| {
+        let mut rng = Random::new();
+        let sampler = BernoulliSampler::new(0.3).unwrap();
+        b.iter(|| black_box(&sampler).sample(&mut rng))
+    });
+
+    // Benchmark bulk byte generation; with `--features simd` this runs
+    // the SSE2/NEON tempering path.
+    c.bench_function("Random fill_bytes 4KiB", |b| {
+        let mut rng = Random::new();
+        let mut buffer = vec![0u8; 4096];
+        b.iter(|| rng.fill_bytes(black_box(&mut buffer)))
+    });
+
+    // Benchmark the same bytes drawn one word at a time, the scalar
+    // baseline for the lane-tempered path above.
+    c.bench_function("Random fill_bytes 4KiB scalar baseline", |b| {
+        let mut rng = Random::new();
+        let mut buffer = vec![0u8; 4096];
+        b.iter(|| {
+            for chunk in black_box(&mut buffer).chunks_mut(4) {
+                chunk.copy_from_slice(&rng.rand().to_le_bytes());
+            }
+        })
+    });
 }
 
 // Groups the benchmarks and runs them using the `criterion_group` macro.
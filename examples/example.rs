@@ -304,7 +304,7 @@ fn demonstrate_shuffling_and_sampling() {
     );
 
     // Example of using the `fill` method to fill a mutable slice with random values.
-    let mut buffer = [0; 10];
+    let mut buffer = [0u32; 10];
     Random::fill(&mut rng, &mut buffer);
     println!("🦀 Filled buffer with random values: {:?}", buffer);
 
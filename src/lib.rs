@@ -100,12 +100,19 @@
 //!- `rand_choose!(rng, values)`: Generate a random element from a slice of values.
 //!- `rand_float!(rng)`: Generate a random float.
 //!- `rand_int!(rng, min, max)`: Generate a random integer within the given range.
-//!- `rand_uint!(rng, min, max)`: Generate a random 32-bit unsigned integer within the given range.
+//!- `rand_uint!(rng, min, max)`: Generate a random 32-bit unsigned integer within the given inclusive range.
+//!- `rand_range_inclusive!(rng, min, max)`: Generate a random 32-bit unsigned integer within the given inclusive range.
+//!- `rand_duration!(rng, min, max)`: Generate a random `Duration` within the given inclusive range.
+//!- `rand_uuid!(rng)`: Generate a random version 4 UUID.
+//!- `rand_hex!(rng, byte_len)`: Generate a lowercase hexadecimal string from `byte_len` random bytes.
+//!- `rand_subset!(rng, slice, p)`: Generate a random subset of a slice, keeping each element with probability `p`.
+//!- `random_range!(rng, min, max)`: Generate a random 32-bit unsigned integer within the given half-open range (`max` is excluded).
 //!- `rand_double!(rng)`: Generate a random double.
 //!- `rand_string!(rng, length)`: Generate a random string of the specified length.
 //!- `rand_alphanumeric!(rng)`: Generate a random alphanumeric character.
 //!- `rand_shuffle!(rng, slice)`: Shuffle a mutable slice randomly.
 //!- `rand_weighted_choice!(rng, choices, weights)`: Select a random element from a slice based on the provided weights.
+//!- `rand_choose_weighted!(rng, choices, weights)`: Select a random element from a slice based on the provided weights, returning a `Result` instead of panicking.
 //!- `rand_normal!(rng, mu, sigma)`: Generate a normally distributed random number with the given mean and standard deviation.
 //!- `rand_exponential!(rng, rate)`: Generate a random number from the exponential distribution with the given rate parameter.
 //!- `rand_poisson!(rng, mean)`: Generate a random number from a Poisson distribution with the specified mean parameter.
@@ -163,7 +170,14 @@
 #![crate_type = "lib"]
 #![warn(missing_docs)]
 #![warn(rust_2018_idioms)]
-#![forbid(unsafe_code)]
+#![cfg_attr(
+    not(any(feature = "uninit", feature = "simd")),
+    forbid(unsafe_code)
+)]
+#![cfg_attr(
+    any(feature = "uninit", feature = "simd"),
+    deny(unsafe_code)
+)]
 #![doc = "Minimum supported Rust version: 1.56.0"]
 
 use rlg::{log::Log, log_format::LogFormat, log_level::LogLevel};
@@ -179,9 +193,15 @@
 /// The `macros` module contains functions for generating macros.
 pub mod macros;
 
+/// The `math` module routes floating-point functions through `std` or, with `portable-math`, `libm`.
+mod math;
+
 /// The `random` module contains the implementation of the `Random` struct.
 pub mod random;
 
+/// The `samplers` module contains precomputed samplers built on top of the `Random` struct.
+pub mod samplers;
+
 /// Custom error type for the `Random (VRD)` library.
 #[derive(Debug)]
 pub enum VrdError {
@@ -213,6 +233,12 @@ fn source(&self) -> Option<&(dyn Error + 'static)> {
     }
 }
 
+impl From<mersenne_twister::MersenneTwisterError> for VrdError {
+    fn from(err: mersenne_twister::MersenneTwisterError) -> VrdError {
+        VrdError::GeneralError(err.to_string())
+    }
+}
+
 /// The main entry point for the `Random (VRD)` library.
 ///
 /// This function performs initial setup and checks before the library can be used. It also provides a basic interface for interacting with the library.
@@ -23,18 +23,40 @@
 //! * [`rand_char!()`](macro.rand_char.html) - Generate a random char within the range 'a'..='z'
 //! * [`rand_choose!()`](macro.rand_choose.html) - Generate a random element from a slice of values
 //! * [`rand_float!()`](macro.rand_float.html) - Generate a random float
+//! * [`rand_hex!()`](macro.rand_hex.html) - Generate a lowercase hexadecimal string from the given number of random bytes
 //! * [`rand_int!()`](macro.rand_int.html) - Generate a random integer within the given range
-//! * [`rand_uint!()`](macro.rand_uint.html) - Generate a random 32-bit unsigned integer within the given range
+//! * [`rand_uint!()`](macro.rand_uint.html) - Generate a random 32-bit unsigned integer within the given inclusive range
+//! * [`random_range!()`](macro.random_range.html) - Generate a random 32-bit unsigned integer within the given half-open range
 //! * [`rand_double!()`](macro.rand_double.html) - Generate a random double
+//! * [`rand_duration!()`](macro.rand_duration.html) - Generate a random `Duration` within the given inclusive range
 //! * [`rand_new!()`](macro.rand_new.html) - Generate a new random number
 //! * [`rand_pseudo!()`](macro.rand_pseudo.html) - Generate a pseudo random number
-//! * [`rand_range!()`](macro.rand_range.html) - Generate a random number within the given range
+//! * [`rand_range!()`](macro.rand_range.html) - Generate a random number within the given inclusive range
+//! * [`rand_range_inclusive!()`](macro.rand_range_inclusive.html) - Generate a random 32-bit unsigned integer within the given inclusive range
 //! * [`rand_seed!()`](macro.rand_seed.html) - Seed the provided `Random (VRD)` struct with the given value
+//! * [`rand_subset!()`](macro.rand_subset.html) - Generate a random subset of a slice, keeping each element with the given probability
 //! * [`rand_twist!()`](macro.rand_twist.html) - Twist the state of the provided `Random (VRD)` struct
+//! * [`rand_uuid!()`](macro.rand_uuid.html) - Generate a random version 4 UUID
 //!
 
 /// Generate a random 32-bit unsigned integer within the given range
 /// using the provided `Random (VRD)` struct
+///
+/// The range is half-open: `min` is inclusive and `max` is exclusive.
+/// Use [`rand_range_inclusive!`] to include `max`.
+///
+/// # Examples
+///
+/// ```
+/// use vrd::random_range;
+/// let mut rng = vrd::random::Random::new();
+/// let value = random_range!(rng, 10, 20);
+/// assert!((10..20).contains(&value));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `min` is not less than `max`.
 #[macro_export]
 macro_rules! random_range {
     ($rng:expr, $min:expr, $max:expr) => {
@@ -109,6 +131,8 @@ macro_rules! rand_float {
 
 /// Generate a random integer within the given range using the provided
 /// `Random (VRD)` struct
+///
+/// Both `min` and `max` are inclusive.
 #[macro_export]
 macro_rules! rand_int {
     ($rng:expr, $min:expr, $max:expr) => {
@@ -118,6 +142,8 @@ macro_rules! rand_int {
 
 /// Generate a random 32-bit unsigned integer within the given range
 /// using the provided `Random (VRD)` struct
+///
+/// Both `min` and `max` are inclusive.
 #[macro_export]
 macro_rules! rand_uint {
     ($rng:expr, $min:expr, $max:expr) => {
@@ -152,6 +178,8 @@ macro_rules! rand_pseudo {
 
 /// Generate a random number within the given range using the provided
 /// `Random (VRD)` struct
+///
+/// The values are `i32` and both `min` and `max` are inclusive.
 #[macro_export]
 macro_rules! rand_range {
     ($rng:expr, $min:expr, $max:expr) => {
@@ -159,6 +187,31 @@ macro_rules! rand_range {
     };
 }
 
+/// Generate a random 32-bit unsigned integer within the given inclusive
+/// range using the provided `Random (VRD)` struct
+///
+/// Both `min` and `max` are inclusive, so `max` itself can be returned.
+/// This is the inclusive counterpart of [`random_range!`].
+///
+/// # Examples
+///
+/// ```
+/// use vrd::rand_range_inclusive;
+/// let mut rng = vrd::random::Random::new();
+/// let value = rand_range_inclusive!(rng, 10, 20);
+/// assert!((10..=20).contains(&value));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `min` is greater than `max`.
+#[macro_export]
+macro_rules! rand_range_inclusive {
+    ($rng:expr, $min:expr, $max:expr) => {
+        $rng.uint($min, $max)
+    };
+}
+
 /// Seed the provided `Random (VRD)` struct with the given value
 #[macro_export]
 macro_rules! rand_seed {
@@ -283,7 +336,8 @@ macro_rules! rand_shuffle {
 /// * `weights` - A reference to the slice of weights corresponding to each element.
 ///
 /// # Panics
-/// Panics if `choices` and `weights` have different lengths.
+/// Panics if `choices` and `weights` have different lengths, if the weights sum to zero, or if their
+/// sum overflows `u32`. Use [`rand_choose_weighted!`] to handle malformed weights without panicking.
 ///
 /// # Returns
 /// A reference to the randomly selected element from `choices`.
@@ -309,6 +363,70 @@ macro_rules! rand_weighted_choice {
     }};
 }
 
+/// Select a random element from a slice based on the provided weights, returning an error instead of
+/// panicking when the input is malformed.
+///
+/// ```
+/// use vrd::rand_choose_weighted;
+/// let mut rng = vrd::random::Random::new();
+/// let choices = ["A", "B", "C"];
+/// assert!(rand_choose_weighted!(rng, &choices, &[2, 3, 5]).is_ok());
+/// assert!(rand_choose_weighted!(rng, &choices, &[0, 0, 0]).is_err());
+/// ```
+///
+/// # Arguments
+/// * `rng` - A mutable reference to a `Random` instance.
+/// * `choices` - A reference to the slice of elements to choose from.
+/// * `weights` - A reference to the slice of `u32` weights corresponding to each element.
+///
+/// # Returns
+/// `Ok` with a reference to the randomly selected element from `choices`, or a
+/// `VrdError::GeneralError` if the slices have different lengths, the weights sum to zero, or their
+/// sum overflows `u32`.
+#[macro_export]
+macro_rules! rand_choose_weighted {
+    ($rng:expr, $choices:expr, $weights:expr) => {{
+        let choices = $choices;
+        let weights = $weights;
+        if choices.len() != weights.len() {
+            Err($crate::VrdError::GeneralError(
+                "Choices and weights must have the same length"
+                    .to_string(),
+            ))
+        } else {
+            match weights
+                .iter()
+                .try_fold(0u32, |total: u32, &weight| {
+                    total.checked_add(weight)
+                }) {
+                None => Err($crate::VrdError::GeneralError(
+                    "Total weight overflows u32".to_string(),
+                )),
+                Some(0) => Err($crate::VrdError::GeneralError(
+                    "Total weight must be greater than zero"
+                        .to_string(),
+                )),
+                Some(total_weight) => {
+                    let mut rnd = $rng.random_range(0, total_weight);
+                    let mut selected_choice = None;
+                    for (index, &weight) in weights.iter().enumerate() {
+                        if rnd < weight {
+                            selected_choice = Some(&choices[index]);
+                            break;
+                        }
+                        rnd -= weight;
+                    }
+                    selected_choice.ok_or_else(|| {
+                        $crate::VrdError::GeneralError(
+                            "Invalid weighted choice".to_string(),
+                        )
+                    })
+                }
+            }
+        }
+    }};
+}
+
 /// Generate a normally distributed random number with the given mean and standard deviation.
 ///
 /// # Examples
@@ -330,10 +448,8 @@ macro_rules! rand_weighted_choice {
 #[macro_export]
 macro_rules! rand_normal {
     ($rng:expr, $mu:expr, $sigma:expr) => {{
-        let u1: f64 = $rng.f64(); // Ensuring f64() method is called on the RNG
-        let u2: f64 = $rng.f64(); // Ensuring f64() method is called on the RNG
-        let z0 = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
-        $mu + $sigma * z0
+        // Delegate to `Random::normal`, which draws with the same Box-Muller transform.
+        $rng.normal($mu, $sigma)
     }};
 }
 
@@ -357,7 +473,7 @@ macro_rules! rand_exponential {
         }
 
         // Implementation of the inverse CDF method for exponential distribution.
-        -1.0 / $rate * (1.0 - $rng.f64()).ln()
+        $rng.exponential($rate)
     }};
 }
 
@@ -394,3 +510,89 @@ macro_rules! rand_poisson {
         k - 1
     }};
 }
+
+/// Generate a random `Duration` within the given inclusive range using
+/// the provided `Random (VRD)` struct
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use vrd::rand_duration;
+/// let mut rng = vrd::random::Random::new();
+/// let min = Duration::from_millis(10);
+/// let max = Duration::from_millis(50);
+/// let delay = rand_duration!(rng, min, max);
+/// assert!(delay >= min && delay <= max);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `min` is greater than `max`.
+#[macro_export]
+macro_rules! rand_duration {
+    ($rng:expr, $min:expr, $max:expr) => {
+        $rng.duration($min, $max)
+    };
+}
+
+/// Generate a random version 4 UUID using the provided `Random (VRD)`
+/// struct
+///
+/// # Examples
+///
+/// ```
+/// use vrd::rand_uuid;
+/// let mut rng = vrd::random::Random::new();
+/// let id = rand_uuid!(rng);
+/// assert_eq!(id.get_version_num(), 4);
+/// ```
+#[macro_export]
+macro_rules! rand_uuid {
+    ($rng:expr) => {
+        $rng.uuid()
+    };
+}
+
+/// Generate a lowercase hexadecimal string from the given number of
+/// random bytes using the provided `Random (VRD)` struct
+///
+/// The string has two characters per byte.
+///
+/// # Examples
+///
+/// ```
+/// use vrd::rand_hex;
+/// let mut rng = vrd::random::Random::new();
+/// let token = rand_hex!(rng, 8);
+/// assert_eq!(token.len(), 16);
+/// ```
+#[macro_export]
+macro_rules! rand_hex {
+    ($rng:expr, $byte_len:expr) => {
+        $rng.random_hex($byte_len)
+    };
+}
+
+/// Generate a random subset of a slice using the provided
+/// `Random (VRD)` struct, keeping each element with probability `p`
+///
+/// # Examples
+///
+/// ```
+/// use vrd::rand_subset;
+/// let mut rng = vrd::random::Random::new();
+/// let values = [1, 2, 3, 4, 5];
+/// let subset = rand_subset!(rng, &values, 0.5);
+/// assert!(subset.len() <= values.len());
+/// ```
+///
+/// # Panics
+///
+/// Panics if `p` is not between 0.0 and 1.0.
+#[macro_export]
+macro_rules! rand_subset {
+    ($rng:expr, $slice:expr, $p:expr) => {
+        $rng.subset($slice, $p)
+    };
+}
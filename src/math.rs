@@ -0,0 +1,95 @@
+// Copyright © 2023-2024 Random (VRD) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// This file is part of the `Random (VRD)` library, a Rust implementation of the Mersenne Twister RNG.
+// See LICENSE-APACHE.md and LICENSE-MIT.md in the repository root for full license information.
+
+//! Floating-point functions used by the distribution samplers.
+//!
+//! The standard library's transcendental functions call the platform's math library, whose results
+//! can differ in the last bit between targets. With the `portable-math` feature these functions are
+//! routed through the pure-Rust `libm` crate instead, so a fixed seed produces bit-identical samples
+//! everywhere. Without the feature they are thin wrappers around the `f64` methods.
+
+/// Returns the natural logarithm of `x`.
+#[cfg(not(feature = "portable-math"))]
+pub(crate) fn ln(x: f64) -> f64 {
+    x.ln()
+}
+
+/// Returns the natural logarithm of `x`.
+#[cfg(feature = "portable-math")]
+pub(crate) fn ln(x: f64) -> f64 {
+    libm::log(x)
+}
+
+/// Returns `ln(1 + x)`, accurate even when `x` is near zero.
+#[cfg(not(feature = "portable-math"))]
+pub(crate) fn ln_1p(x: f64) -> f64 {
+    x.ln_1p()
+}
+
+/// Returns `ln(1 + x)`, accurate even when `x` is near zero.
+#[cfg(feature = "portable-math")]
+pub(crate) fn ln_1p(x: f64) -> f64 {
+    libm::log1p(x)
+}
+
+/// Returns the base 2 logarithm of `x`.
+#[cfg(not(feature = "portable-math"))]
+pub(crate) fn log2(x: f64) -> f64 {
+    x.log2()
+}
+
+/// Returns the base 2 logarithm of `x`.
+#[cfg(feature = "portable-math")]
+pub(crate) fn log2(x: f64) -> f64 {
+    libm::log2(x)
+}
+
+/// Returns `e^x`.
+#[cfg(not(feature = "portable-math"))]
+pub(crate) fn exp(x: f64) -> f64 {
+    x.exp()
+}
+
+/// Returns `e^x`.
+#[cfg(feature = "portable-math")]
+pub(crate) fn exp(x: f64) -> f64 {
+    libm::exp(x)
+}
+
+/// Returns `x` raised to the power `y`.
+#[cfg(not(feature = "portable-math"))]
+pub(crate) fn powf(x: f64, y: f64) -> f64 {
+    x.powf(y)
+}
+
+/// Returns `x` raised to the power `y`.
+#[cfg(feature = "portable-math")]
+pub(crate) fn powf(x: f64, y: f64) -> f64 {
+    libm::pow(x, y)
+}
+
+/// Returns the square root of `x`.
+#[cfg(not(feature = "portable-math"))]
+pub(crate) fn sqrt(x: f64) -> f64 {
+    x.sqrt()
+}
+
+/// Returns the square root of `x`.
+#[cfg(feature = "portable-math")]
+pub(crate) fn sqrt(x: f64) -> f64 {
+    libm::sqrt(x)
+}
+
+/// Returns the cosine of `x` radians.
+#[cfg(not(feature = "portable-math"))]
+pub(crate) fn cos(x: f64) -> f64 {
+    x.cos()
+}
+
+/// Returns the cosine of `x` radians.
+#[cfg(feature = "portable-math")]
+pub(crate) fn cos(x: f64) -> f64 {
+    libm::cos(x)
+}
@@ -7,7 +7,7 @@
 use std::{
     fmt,
     fs::File,
-    io::{self, BufReader, BufWriter},
+    io::{self, BufReader, BufWriter, Read, Write},
     path::Path,
 };
 
@@ -83,15 +83,41 @@ pub struct MersenneTwisterParams {
     pub tempering_mask_c: u32,
 }
 
+impl MersenneTwisterParams {
+    /// The parameters of the reference MT19937 generator of Matsumoto and Nishimura.
+    ///
+    /// This is the only published parameter set that currently passes `MersenneTwisterConfig::validate`,
+    /// which requires the MT19937 masks. Variants such as TinyMT or MT11213 use different word sizes,
+    /// masks or state lengths and are not offered as presets until validation supports them.
+    pub const MT19937: MersenneTwisterParams = MersenneTwisterParams {
+        matrix_a: 0x9908b0df,
+        upper_mask: 0x80000000,
+        lower_mask: 0x7fffffff,
+        tempering_mask_b: 0x9d2c5680,
+        tempering_mask_c: 0xefc60000,
+    };
+
+    /// Returns the MT19937 parameter set, the same as [`MersenneTwisterParams::MT19937`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vrd::mersenne_twister::{MersenneTwisterConfig, MersenneTwisterParams};
+    ///
+    /// let config = MersenneTwisterConfig::<624, 397>::new_custom(
+    ///     MersenneTwisterParams::mt19937(),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(config.params, MersenneTwisterParams::default());
+    /// ```
+    pub const fn mt19937() -> Self {
+        Self::MT19937
+    }
+}
+
 impl Default for MersenneTwisterParams {
     fn default() -> Self {
-        MersenneTwisterParams {
-            matrix_a: 0x9908b0df,
-            upper_mask: 0x80000000,
-            lower_mask: 0x7fffffff,
-            tempering_mask_b: 0x9d2c5680,
-            tempering_mask_c: 0xefc60000,
-        }
+        Self::MT19937
     }
 }
 
@@ -206,6 +232,58 @@ pub fn validate(
         Ok(())
     }
 
+    /// Checks that the configuration's `N` and `M` match the state array of a generator.
+    ///
+    /// [`Random`](crate::random::Random) always has a 624-word state and a shift of 397, so a configuration
+    /// with other const parameters cannot drive it. This reports the mismatch at runtime instead of letting
+    /// the generator silently ignore `N` and `M`.
+    ///
+    /// # Arguments
+    ///
+    /// * `state_len` - The number of words in the generator's state array.
+    /// * `shift` - The twist offset the generator uses.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or failure.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `MersenneTwisterError::InvalidConfig` naming the expected and actual value if `N` differs
+    /// from `state_len` or `M` differs from `shift`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vrd::mersenne_twister::MersenneTwisterConfig;
+    ///
+    /// let config = MersenneTwisterConfig::<312, 156>::new().unwrap();
+    /// let error = config.validate_dimensions(624, 397).unwrap_err();
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "Invalid configuration: n must be 624 to match the state array, but the config has n = 312"
+    /// );
+    /// ```
+    pub fn validate_dimensions(
+        &self,
+        state_len: usize,
+        shift: usize,
+    ) -> Result<(), MersenneTwisterError> {
+        if N != state_len {
+            return Err(MersenneTwisterError::InvalidConfig(format!(
+                "n must be {} to match the state array, but the config has n = {}",
+                state_len, N
+            )));
+        }
+        if M != shift {
+            return Err(MersenneTwisterError::InvalidConfig(format!(
+                "m must be {} to match the generator, but the config has m = {}",
+                shift, M
+            )));
+        }
+        Ok(())
+    }
+
     /// Creates a new `MersenneTwisterConfig` with default values.
     ///
     /// Default values are as follows:
@@ -271,6 +349,33 @@ pub fn set_config(
         Ok(())
     }
 
+    /// Returns a structured report of this configuration for auditing and diffing.
+    ///
+    /// # Returns
+    ///
+    /// A `ConfigReport` holding the parameters, the `N` and `M` dimensions, and whether they match the
+    /// MT19937 defaults.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vrd::mersenne_twister::MersenneTwisterConfig;
+    ///
+    /// let report = MersenneTwisterConfig::<624, 397>::default().describe();
+    /// assert!(report.is_default);
+    /// println!("{}", serde_json::to_string_pretty(&report).unwrap());
+    /// ```
+    pub fn describe(&self) -> ConfigReport {
+        ConfigReport {
+            params: self.params,
+            n: N,
+            m: M,
+            is_default: N == 624
+                && M == 397
+                && self.params == MersenneTwisterParams::default(),
+        }
+    }
+
     /// Serialize a `MersenneTwisterConfig` instance to a JSON file.
     ///
     /// # Arguments
@@ -298,10 +403,7 @@ pub fn serialize_to_file(
         filename: &str,
     ) -> Result<(), MersenneTwisterError> {
         let file = File::create(filename)?;
-        let writer = BufWriter::new(file);
-        serde_json::to_writer(writer, &self).map_err(|e| {
-            MersenneTwisterError::SerializationError(e.to_string())
-        })
+        self.serialize_to_writer(BufWriter::new(file))
     }
 
     /// Deserialize a `MersenneTwisterConfig` instance from a JSON file.
@@ -342,7 +444,74 @@ pub fn deserialize_from_file<P: AsRef<Path>>(
                 ),
             ))
         })?;
-        let reader = BufReader::new(file);
+        Self::deserialize_from_reader(BufReader::new(file))
+    }
+
+    /// Serialize a `MersenneTwisterConfig` instance as JSON to any writer.
+    ///
+    /// The writer can be a socket, a pipe or an in-memory buffer. It is not buffered or flushed, so wrap
+    /// unbuffered writers in a `BufWriter` where that matters.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The destination for the JSON data.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` indicating success or failure.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `MersenneTwisterError::SerializationError` if serialization or writing fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vrd::mersenne_twister::MersenneTwisterConfig;
+    ///
+    /// let config = MersenneTwisterConfig::<624, 397>::new().unwrap();
+    /// let mut buffer = Vec::new();
+    /// config.serialize_to_writer(&mut buffer).unwrap();
+    /// assert!(!buffer.is_empty());
+    /// ```
+    pub fn serialize_to_writer<W: Write>(
+        &self,
+        writer: W,
+    ) -> Result<(), MersenneTwisterError> {
+        serde_json::to_writer(writer, &self).map_err(|e| {
+            MersenneTwisterError::SerializationError(e.to_string())
+        })
+    }
+
+    /// Deserialize a `MersenneTwisterConfig` instance from JSON read from any reader.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - The source of the JSON data.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the deserialized `MersenneTwisterConfig` instance or an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `MersenneTwisterError::SerializationError` if reading or deserialization fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vrd::mersenne_twister::MersenneTwisterConfig;
+    ///
+    /// let config = MersenneTwisterConfig::<624, 397>::new().unwrap();
+    /// let mut buffer = Vec::new();
+    /// config.serialize_to_writer(&mut buffer).unwrap();
+    /// let restored =
+    ///     MersenneTwisterConfig::<624, 397>::deserialize_from_reader(buffer.as_slice()).unwrap();
+    /// assert_eq!(restored, config);
+    /// ```
+    pub fn deserialize_from_reader<R: Read>(
+        reader: R,
+    ) -> Result<Self, MersenneTwisterError> {
         serde_json::from_reader(reader).map_err(|e| {
             MersenneTwisterError::SerializationError(e.to_string())
         })
@@ -429,6 +598,21 @@ pub fn deserialize_from_toml(
     }
 }
 
+/// A structured description of a `MersenneTwisterConfig`, produced by `MersenneTwisterConfig::describe`.
+#[derive(
+    Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize,
+)]
+pub struct ConfigReport {
+    /// The configuration parameters.
+    pub params: MersenneTwisterParams,
+    /// The number of elements in the state array, the config's `N`.
+    pub n: usize,
+    /// The offset used by the recurrence, the config's `M`.
+    pub m: usize,
+    /// Whether the parameters and dimensions are the MT19937 defaults.
+    pub is_default: bool,
+}
+
 impl Default for MersenneTwisterConfig<624, 397> {
     fn default() -> Self {
         MersenneTwisterConfig::new().unwrap()
@@ -3,10 +3,34 @@
 // This file is part of the `Random (VRD)` library, a Rust implementation of the Mersenne Twister RNG.
 // See LICENSE-APACHE.md and LICENSE-MIT.md in the repository root for full license information.
 
-use crate::MersenneTwisterConfig;
+use crate::math;
+use crate::mersenne_twister::MersenneTwisterParams;
+use crate::{MersenneTwisterConfig, VrdError};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
 use rand::{RngCore, SeedableRng};
 use serde::{Deserialize, Serialize};
 use serde_big_array::BigArray;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::{Hash, Hasher};
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::time::Duration;
+
+/// The canonical MT19937 seed used when a generator is drawn from without being seeded.
+const DEFAULT_SEED: u32 = 5489;
+
+/// The `mti` sentinel marking a generator that has never been seeded.
+///
+/// Valid indices run from `0` to `624`, where `624` means the block is used up and the next draw twists.
+/// One past that, `625`, is reserved for [`Random::blank`]; drawing from such a generator seeds it with
+/// `DEFAULT_SEED` first, as the reference implementation does.
+const UNSEEDED_MTI: usize = 625;
+
+/// Tolerance used when checking that a probability mass function sums to 1.0.
+const PMF_TOLERANCE: f64 = 1e-6;
 
 #[non_exhaustive]
 #[derive(
@@ -23,12 +47,41 @@
 /// The `Random` struct is used to generate random numbers using the Mersenne Twister algorithm.
 ///
 /// This struct maintains an internal state for random number generation and provides methods to generate various types of random numbers.
+///
+/// The derived `Eq`, `Ord` and `Hash` implementations compare the raw `mt`, `mti` and `params` fields. A
+/// generator that is due to twist (`mti == 624`) and the same generator after an explicit [`Random::twist`]
+/// produce identical output but compare as different; use [`Random::canonical`] to compare generators by
+/// their future stream instead, [`Random::produces_same_as`] to compare a prefix of their actual output,
+/// and [`Random::sequence_hash`] to hash them that way.
 pub struct Random {
     /// The array of unsigned 32-bit integers used to generate random numbers.
     #[serde(with = "BigArray")]
     pub mt: [u32; 624],
     /// The current index of the array used in the generation of random numbers.
     pub mti: usize,
+    /// The Mersenne Twister parameters used for twisting and tempering.
+    #[serde(default)]
+    pub params: MersenneTwisterParams,
+    /// The output word left partially consumed by [`Random::discard_bytes`].
+    #[serde(default)]
+    pending_word: u32,
+    /// The index of the next unused byte of `pending_word`, or `0` when no partial word is pending.
+    #[serde(default)]
+    pending_offset: u8,
+    /// The unused bits of the last word drawn by [`Random::bits_be`] or [`Random::bits_le`], left-aligned.
+    #[serde(default)]
+    bit_word: u32,
+    /// The number of unused bits held in `bit_word`.
+    #[serde(default)]
+    bit_count: u8,
+    /// The number of raw outputs drawn from the state array, available with the `counters` feature.
+    #[cfg(feature = "counters")]
+    #[serde(default)]
+    draws: u64,
+    /// The most recent value returned by `rand()`, available with the `counters` feature.
+    #[cfg(feature = "counters")]
+    #[serde(default)]
+    last: Option<u32>,
 }
 
 impl Random {
@@ -40,7 +93,7 @@ impl Random {
     ///
     /// # Arguments
     /// * `probability` - A `f64` value representing the probability of the function returning `true`.
-    ///                   This should be a value between 0.0 and 1.0, where 0.0 always returns `false` and 1.0 always returns `true`.
+    ///   This should be a value between 0.0 and 1.0, where 0.0 always returns `false` and 1.0 always returns `true`.
     ///
     /// # Examples
     /// ```
@@ -49,13 +102,170 @@ impl Random {
     /// let random_bool = rng.bool(0.5); // 50% chance to get true
     /// ```
     ///
+    /// # Notes
+    /// - When `probability` is exactly `0.5`, the result is taken from the top bit of a single `rand()`
+    ///   draw, which gives the same outcome as the float comparison without the conversion.
+    /// - Every call consumes exactly one `rand()` draw.
+    ///
     /// # Panics
     /// Panics if `probability` is not between 0.0 and 1.0.
     pub fn bool(&mut self, probability: f64) -> bool {
         let random_value = self.rand();
+        if probability == 0.5 {
+            return random_value >> 31 == 0;
+        }
         (random_value as f64) < (probability * u32::MAX as f64)
     }
 
+    /// Returns `-1` or `1` with equal probability.
+    ///
+    /// The sign is taken from the top bit of a single `rand()` draw, which makes this a Rademacher random
+    /// variable, as used by SPSA and random projections.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let sign = rng.random_sign();
+    /// assert!(sign == -1 || sign == 1);
+    /// ```
+    ///
+    /// # Returns
+    /// An `i32` that is either `-1` or `1`.
+    pub fn random_sign(&mut self) -> i32 {
+        if self.rand() >> 31 == 0 {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Generates a vector of independent Rademacher values.
+    ///
+    /// # Arguments
+    /// * `n` - The length of the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let perturbation = rng.rademacher_vec(8);
+    /// assert!(perturbation.iter().all(|&x| x == -1 || x == 1));
+    /// ```
+    ///
+    /// # Returns
+    /// A `Vec<i32>` of `n` values, each drawn with [`Random::random_sign`].
+    pub fn rademacher_vec(&mut self, n: usize) -> Vec<i32> {
+        (0..n).map(|_| self.random_sign()).collect()
+    }
+
+    /// Generates a simple random walk starting at 0.0, where every step moves up or down by `step_size`.
+    ///
+    /// Each direction is drawn with [`Random::random_sign`], so every step consumes one `rand()` draw. Use
+    /// [`Random::random_walk_normal`] for normally distributed increments instead.
+    ///
+    /// # Arguments
+    /// * `steps` - The number of steps to take.
+    /// * `step_size` - The distance moved on each step.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let path = rng.random_walk(100, 0.5);
+    /// assert_eq!(path.len(), 101);
+    /// assert_eq!(path[0], 0.0);
+    /// ```
+    ///
+    /// # Returns
+    /// A `Vec<f64>` of `steps + 1` positions, beginning with the starting point 0.0.
+    pub fn random_walk(
+        &mut self,
+        steps: usize,
+        step_size: f64,
+    ) -> Vec<f64> {
+        self.walk(steps, |rng| f64::from(rng.random_sign()) * step_size)
+    }
+
+    /// Generates a Gaussian random walk starting at 0.0, where every increment is normal with mean 0.0.
+    ///
+    /// # Arguments
+    /// * `steps` - The number of steps to take.
+    /// * `sigma` - The standard deviation of each increment.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let prices = rng.random_walk_normal(250, 1.0);
+    /// assert_eq!(prices.len(), 251);
+    /// ```
+    ///
+    /// # Returns
+    /// A `Vec<f64>` of `steps + 1` positions, beginning with the starting point 0.0.
+    pub fn random_walk_normal(
+        &mut self,
+        steps: usize,
+        sigma: f64,
+    ) -> Vec<f64> {
+        self.walk(steps, |rng| rng.normal(0.0, sigma))
+    }
+
+    /// Accumulates `steps` increments drawn by `increment` into a path starting at 0.0.
+    fn walk<F: FnMut(&mut Random) -> f64>(
+        &mut self,
+        steps: usize,
+        mut increment: F,
+    ) -> Vec<f64> {
+        let mut path = Vec::with_capacity(steps + 1);
+        let mut position = 0.0;
+        path.push(position);
+        for _ in 0..steps {
+            position += increment(self);
+            path.push(position);
+        }
+        path
+    }
+
+    /// Simulates the coupon collector's problem and returns the number of draws needed to collect all
+    /// `n` distinct coupons.
+    ///
+    /// Coupon indices are drawn uniformly from `0..n` with [`Random::random_range`] until every index has
+    /// been seen. The expected result is `n * H(n)`, where `H(n)` is the `n`-th harmonic number, so it
+    /// grows like `n ln n`.
+    ///
+    /// # Arguments
+    /// * `n` - The number of distinct coupons. Must be positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let draws = rng.coupon_collector(6);
+    /// assert!(draws >= 6);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    ///
+    /// # Returns
+    /// The number of draws, which is always at least `n`.
+    pub fn coupon_collector(&mut self, n: u32) -> u64 {
+        assert!(n > 0, "n must be positive for coupon_collector");
+        let mut seen = vec![false; n as usize];
+        let mut missing = n;
+        let mut draws = 0u64;
+        while missing > 0 {
+            draws += 1;
+            let index = self.random_range(0, n) as usize;
+            if !seen[index] {
+                seen[index] = true;
+                missing -= 1;
+            }
+        }
+        draws
+    }
+
     /// Generates a vector of random bytes of the specified length.
     ///
     /// # Arguments
@@ -73,13 +283,163 @@ pub fn bool(&mut self, probability: f64) -> bool {
     /// A `Vec<u8>` containing `len` randomly generated bytes.
     pub fn bytes(&mut self, len: usize) -> Vec<u8> {
         let mut res = Vec::with_capacity(len);
+        self.bytes_into(&mut res, len);
+        res
+    }
+
+    /// Clears `buf` and refills it with `len` random bytes, reusing its existing capacity.
+    ///
+    /// Produces the same bytes as [`Random::bytes`], but lets a caller generating many byte vectors in a
+    /// loop keep one allocation instead of creating a new `Vec` on every call.
+    ///
+    /// # Arguments
+    /// * `buf` - The buffer to overwrite. Any previous contents are discarded.
+    /// * `len` - The number of random bytes to write into `buf`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let mut buf = Vec::new();
+    /// for len in [16, 4, 8] {
+    ///     rng.bytes_into(&mut buf, len);
+    ///     assert_eq!(buf.len(), len);
+    /// }
+    /// ```
+    pub fn bytes_into(&mut self, buf: &mut Vec<u8>, len: usize) {
+        buf.clear();
+        buf.reserve(len);
         for _ in 0..len {
             let byte = self.rand() as u8;
-            res.push(byte);
+            buf.push(byte);
+        }
+    }
+
+    /// Generates random bytes into a fixed-capacity `heapless::Vec`.
+    ///
+    /// The bytes are drawn exactly as [`Random::bytes`] draws them, one `rand()` per byte, so the result
+    /// matches the start of `bytes(len)` for the same state. Embedded callers can generate into storage
+    /// whose size is fixed at compile time without a heap allocation.
+    ///
+    /// # Arguments
+    /// * `len` - The number of bytes requested.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let buf = rng.bytes_heapless::<16>(64);
+    /// assert_eq!(buf.len(), 16);
+    /// ```
+    ///
+    /// # Notes
+    /// - Only available with the `heapless` feature.
+    /// - The output is silently truncated to the capacity: `min(len, N)` bytes are generated, and only
+    ///   that many `rand()` draws are consumed.
+    ///
+    /// # Returns
+    /// A `heapless::Vec<u8, N>` holding `min(len, N)` random bytes.
+    #[cfg(feature = "heapless")]
+    pub fn bytes_heapless<const N: usize>(
+        &mut self,
+        len: usize,
+    ) -> heapless::Vec<u8, N> {
+        let mut buf = heapless::Vec::new();
+        for _ in 0..len.min(N) {
+            // Cannot fail: at most `N` bytes are pushed.
+            let _ = buf.push(self.rand() as u8);
         }
+        buf
+    }
+
+    /// Generates a fixed-size array of random bytes on the stack.
+    ///
+    /// Unlike [`Random::bytes`], which spends a whole `rand()` draw on each byte, `byte_array` fills the
+    /// array the same way as `fill_bytes`, using all four little-endian bytes of each draw. This suits
+    /// keys, nonces and identifiers whose size is known at compile time without allocating a `Vec`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let nonce: [u8; 12] = rng.byte_array();
+    /// println!("Nonce: {:?}", nonce);
+    /// ```
+    ///
+    /// # Returns
+    /// A `[u8; K]` containing `K` randomly generated bytes.
+    pub fn byte_array<const K: usize>(&mut self) -> [u8; K] {
+        let mut res = [0u8; K];
+        self.fill_bytes(&mut res);
         res
     }
 
+    /// Generates a random lowercase hexadecimal string from `byte_len` random bytes.
+    ///
+    /// The bytes are drawn the same way as `fill_bytes`, four per `rand()` draw, and each byte becomes two
+    /// hex digits, most significant nibble first.
+    ///
+    /// # Arguments
+    /// * `byte_len` - The number of random bytes to encode.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let token = rng.random_hex(16);
+    /// assert_eq!(token.len(), 32);
+    /// ```
+    ///
+    /// # Returns
+    /// A `String` of `2 * byte_len` characters from `0-9a-f`.
+    pub fn random_hex(&mut self, byte_len: usize) -> String {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+        let mut bytes = vec![0u8; byte_len];
+        self.fill_bytes(&mut bytes);
+        let mut hex = String::with_capacity(byte_len * 2);
+        for byte in bytes {
+            hex.push(char::from(DIGITS[usize::from(byte >> 4)]));
+            hex.push(char::from(DIGITS[usize::from(byte & 0x0f)]));
+        }
+        hex
+    }
+
+    /// Generates a byte vector by alternately drawing bytes from this generator and another one.
+    ///
+    /// Bytes at even positions come from `self` and bytes at odd positions come from `other`, each drawn the
+    /// same way as [`Random::bytes`]. The combined stream is reproducible given the states of both generators.
+    ///
+    /// # Arguments
+    /// * `other` - The second generator to interleave with.
+    /// * `len` - The length of the byte vector to be generated.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut a = Random::with_seed(1);
+    /// let mut b = Random::with_seed(2);
+    /// let key = a.interleaved_bytes(&mut b, 32);
+    /// assert_eq!(key.len(), 32);
+    /// ```
+    ///
+    /// # Returns
+    /// A `Vec<u8>` containing `len` bytes taken alternately from both generators.
+    pub fn interleaved_bytes(
+        &mut self,
+        other: &mut Random,
+        len: usize,
+    ) -> Vec<u8> {
+        (0..len)
+            .map(|i| {
+                if i % 2 == 0 {
+                    self.rand() as u8
+                } else {
+                    other.rand() as u8
+                }
+            })
+            .collect()
+    }
+
     /// Generates a random character within the range 'a' to 'z'.
     ///
     /// # Examples
@@ -97,6 +457,58 @@ pub fn char(&mut self) -> char {
         (b'a' + random_value as u8) as char
     }
 
+    /// Generates a random `char` uniformly distributed over the Unicode scalar values in `low..=high`.
+    ///
+    /// The UTF-16 surrogate code points `0xD800..=0xDFFF` are not scalar values, so they are skipped and
+    /// every valid `char` in the range is equally likely. Draws that would bias the result are rejected
+    /// and redrawn.
+    ///
+    /// # Arguments
+    /// * `low` - The lowest `char` that can be returned.
+    /// * `high` - The highest `char` that can be returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let greek = rng.char_in_range('α', 'ω');
+    /// assert!(('α'..='ω').contains(&greek));
+    /// ```
+    ///
+    /// # Returns
+    /// A `char` between `low` and `high`, inclusive.
+    ///
+    /// # Panics
+    /// Panics if `low` is greater than `high`.
+    pub fn char_in_range(&mut self, low: char, high: char) -> char {
+        const SURROGATE_START: u32 = 0xD800;
+        const SURROGATE_LEN: u32 = 0x800;
+        assert!(
+            low <= high,
+            "low must not exceed high for char_in_range"
+        );
+        let (low, high) = (u32::from(low), u32::from(high));
+        let gap = if low < SURROGATE_START && high > SURROGATE_START {
+            SURROGATE_LEN
+        } else {
+            0
+        };
+        let span = u64::from(high - low - gap) + 1;
+        // Only accept draws below the largest multiple of `span` to avoid modulo bias.
+        let limit = (1u64 << 32) / span * span;
+        let offset = loop {
+            let value = u64::from(self.rand());
+            if value < limit {
+                break (value % span) as u32;
+            }
+        };
+        let mut scalar = low + offset;
+        if gap > 0 && scalar >= SURROGATE_START {
+            scalar += SURROGATE_LEN;
+        }
+        char::from_u32(scalar).unwrap_or(char::REPLACEMENT_CHARACTER)
+    }
+
     /// Selects a random element from a provided slice.
     ///
     /// # Arguments
@@ -114,14 +526,145 @@ pub fn char(&mut self) -> char {
     /// # Returns
     /// An `Option<&T>` which is `Some(&T)` if the slice is not empty, containing a randomly chosen element from the slice.
     /// Returns `None` if the slice is empty.
-    pub fn choose<'a, T>(&'a mut self, values: &'a [T]) -> Option<&T> {
+    ///
+    /// # Notes
+    /// - The index is drawn with rejection sampling, so every element is equally likely even when the
+    ///   length is not a power of two. Draws that would bias the result are redrawn, which makes the
+    ///   number of `rand()` calls variable: one in the common case, and more with a probability below
+    ///   `len / 2^32` per draw. Use [`Random::choose_fast`] when a fixed draw count matters more than
+    ///   exact uniformity.
+    /// - An empty slice consumes no draws.
+    pub fn choose<'a, T>(
+        &'a mut self,
+        values: &'a [T],
+    ) -> Option<&'a T> {
         if values.is_empty() {
             return None;
         }
-        let index = (self.rand() as usize) % values.len();
+        let index = self.index_below(values.len());
         Some(&values[index])
     }
 
+    /// Selects a random element from a provided slice using a fixed number of draws.
+    ///
+    /// The index is computed with Lemire's multiply-shift reduction, `(x * len) >> 32` for a single
+    /// `rand()` output `x` (or `(x * len) >> 64` for a `u64` when the slice has more than `2^32`
+    /// elements), without the rejection step that makes it exact.
+    ///
+    /// # Arguments
+    /// * `values` - A slice of values from which to select a random element.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let items = [1, 2, 3, 4, 5];
+    /// assert!(rng.choose_fast(&items).is_some());
+    /// ```
+    ///
+    /// # Returns
+    /// An `Option<&T>` which is `Some(&T)` if the slice is not empty, containing a randomly chosen element from the slice.
+    /// Returns `None` if the slice is empty.
+    ///
+    /// # Notes
+    /// - Exactly one `rand()` draw is consumed for slices of up to `2^32` elements and two for longer
+    ///   ones, which keeps generators in lockstep regardless of the values drawn.
+    /// - Unless the length is a power of two, some elements are chosen with probability higher than
+    ///   others by at most `1 / 2^32` (or `1 / 2^64` for the long-slice path). Use [`Random::choose`] when
+    ///   exact uniformity is required.
+    pub fn choose_fast<'a, T>(
+        &mut self,
+        values: &'a [T],
+    ) -> Option<&'a T> {
+        if values.is_empty() {
+            return None;
+        }
+        let len = values.len() as u128;
+        let index = if len <= 1 << 32 {
+            (u128::from(self.rand()) * len) >> 32
+        } else {
+            (u128::from(self.u64()) * len) >> 64
+        };
+        Some(&values[index as usize])
+    }
+
+    /// Draws an index uniformly from `0..len` with rejection sampling. `len` must be positive.
+    pub(crate) fn index_below(&mut self, len: usize) -> usize {
+        self.uniform_u128_inclusive((len - 1) as u128) as usize
+    }
+
+    /// Selects a random element from a provided mutable slice and returns a mutable reference to it.
+    ///
+    /// The index is drawn exactly as in [`Random::choose`], so both methods select the same position under
+    /// the same generator state.
+    ///
+    /// # Arguments
+    /// * `values` - A mutable slice of values from which to select a random element.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let mut items = [1, 2, 3, 4, 5];
+    /// if let Some(item) = rng.choose_mut(&mut items) {
+    ///     *item *= 10;
+    /// }
+    /// println!("Updated items: {:?}", items);
+    /// ```
+    ///
+    /// # Returns
+    /// An `Option<&mut T>` which is `Some(&mut T)` if the slice is not empty, containing a randomly chosen element.
+    /// Returns `None` if the slice is empty.
+    pub fn choose_mut<'a, T>(
+        &mut self,
+        values: &'a mut [T],
+    ) -> Option<&'a mut T> {
+        if values.is_empty() {
+            return None;
+        }
+        let index = self.index_below(values.len());
+        Some(&mut values[index])
+    }
+
+    /// Selects two elements at distinct random positions from a provided slice.
+    ///
+    /// The first index is drawn over the whole slice and the second over the remaining `len - 1` positions,
+    /// skipping past the first, so no retry loop is needed to keep the two positions apart. Both indices are
+    /// drawn with rejection sampling as in [`Random::choose`], so every pair is equally likely and at least
+    /// two `rand()` calls are consumed.
+    ///
+    /// # Arguments
+    /// * `values` - A slice of values from which to select two elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let parents = ["a", "b", "c", "d"];
+    /// if let Some((first, second)) = rng.choose_two_distinct(&parents) {
+    ///     println!("Crossover between {} and {}", first, second);
+    /// }
+    /// ```
+    ///
+    /// # Returns
+    /// An `Option<(&T, &T)>` which is `Some` with references to elements at two different indices, or `None`
+    /// if the slice has fewer than two elements.
+    pub fn choose_two_distinct<'a, T>(
+        &mut self,
+        values: &'a [T],
+    ) -> Option<(&'a T, &'a T)> {
+        let len = values.len();
+        if len < 2 {
+            return None;
+        }
+        let first = self.index_below(len);
+        let mut second = self.index_below(len - 1);
+        if second >= first {
+            second += 1;
+        }
+        Some((&values[first], &values[second]))
+    }
+
     /// Generates a random floating-point number in the range [0.0, 1.0).
     ///
     /// # Examples
@@ -186,9 +729,10 @@ pub fn int(&mut self, min: i32, max: i32) -> i32 {
             min <= max,
             "min must be less than or equal to max for int"
         );
-        let range = max as u32 - min as u32 + 1;
-        let value_in_range = (self.rand() % range) + min as u32;
-        value_in_range as i32
+        // The span of `i32::MIN..=i32::MAX` is 2^32, so it is computed in 64 bits to avoid overflow.
+        let span = (i64::from(max) - i64::from(min)) as u64 + 1;
+        let offset = u64::from(self.rand()) % span;
+        (i64::from(min) + offset as i64) as i32
     }
 
     /// Generates a random unsigned integer within a specified range.
@@ -223,6 +767,10 @@ pub fn uint(&mut self, min: u32, max: u32) -> u32 {
         let range = max - min;
         assert!(range > 0, "Range should be non-zero");
 
+        // The full `u32` span has 2^32 values, which `range + 1` cannot represent.
+        if range == u32::MAX {
+            return self.rand();
+        }
         (self.rand() % (range + 1)) + min
     }
 
@@ -240,9 +788,14 @@ pub fn uint(&mut self, min: u32, max: u32) -> u32 {
     /// A `f64` representing a randomly generated double-precision floating-point number.
     ///
     /// # Notes
-    /// The generated double is a number in the range [0.0, 1.0).
+    /// - The generated double is a number in the range [0.0, 1.0).
+    /// - Two `rand()` draws supply 27 and 26 bits, `(a * 2^26 + b) / 2^53`, so all 53 bits of the mantissa
+    ///   are random and every multiple of `2^-53` in the range is equally likely. This is the `genrand_res53`
+    ///   construction of the reference implementation.
     pub fn double(&mut self) -> f64 {
-        (self.rand() as f64) / (u32::MAX as f64)
+        let high = u64::from(self.rand() >> 5);
+        let low = u64::from(self.rand() >> 6);
+        ((high << 26) | low) as f64 / 9_007_199_254_740_992.0
     }
 
     /// Returns the current index of the internal state array used in random number generation.
@@ -283,523 +836,4475 @@ pub fn set_mti(&mut self, value: usize) {
         self.mti = value;
     }
 
-    /// Creates a new instance of the `Random` struct, initializing the internal state for random number generation.
-    ///
-    /// This method seeds the random number generator with a default value obtained from the thread's random number generator.
+    /// Returns a read-only view of the internal state array and the current index.
     ///
-    /// The `new` method initializes the `Random` struct. It sets the initial state of the `mt` array
-    /// using a default seed obtained from the system's RNG. This seeding process is crucial for ensuring
-    /// that each instance of `Random` produces a unique and unpredictable sequence of numbers.
+    /// Unlike [`Random::state`], nothing is copied: the array is borrowed for as long as the view is held.
+    /// If `mti` is below 624, the next `rand()` tempers and returns `block[mti]`; at 624 or more, the block
+    /// has been used up and the next draw twists it first.
     ///
     /// # Examples
     /// ```
     /// use vrd::random::Random;
-    /// let mut rng = Random::new(); // Creates a new instance of Random
-    /// let random_number = rng.rand(); // Generates a random number
-    /// println!("Random number: {}", random_number);
+    /// let mut rng = Random::with_seed(42);
+    /// let _ = rng.rand();
+    /// let (block, mti) = rng.current_block();
+    /// let expected = Random::temper(block[mti], &rng.params);
+    /// assert_eq!(rng.rand(), expected);
     /// ```
     ///
     /// # Returns
-    /// A new instance of `Random` with its internal state initialized for random number generation.
-    pub fn new() -> Self {
-        const N: usize = 624;
-        let mut rng = Random {
-            mt: [0; N],
-            mti: N + 1,
-        };
-        let seed = rand::thread_rng().next_u32();
-        rng.mt[0] = seed;
-        for i in 1..N {
-            let previous_value = rng.mt[i - 1];
-            rng.mt[i] = 1812433253u32
-                .wrapping_mul(previous_value ^ (previous_value >> 30))
-                .wrapping_add(i as u32);
-        }
-        rng.mti = N;
-        rng
+    /// A tuple of a reference to the 624-word state array and the current index.
+    pub fn current_block(&self) -> (&[u32; 624], usize) {
+        (&self.mt, self.mti)
     }
 
-    /// Generates a pseudo-random number by combining multiple random number generations.
-    ///
-    /// This method enhances the randomness by XOR-ing multiple calls to the basic random number generator.
+    /// Returns a copy of the internal state array and index.
     ///
     /// # Examples
     /// ```
     /// use vrd::random::Random;
-    /// let mut rng = Random::new();
-    /// let pseudo_random_number = rng.pseudo(); // Generates a pseudo-random number
-    /// println!("Pseudo-random number: {}", pseudo_random_number);
+    /// let rng = Random::with_seed(42);
+    /// let (mt, mti) = rng.state();
+    /// assert_eq!(mt, rng.mt);
+    /// assert_eq!(mti, rng.mti());
     /// ```
     ///
     /// # Returns
-    /// A `u32` representing a pseudo-random number generated by combining multiple random number generations.
-    pub fn pseudo(&mut self) -> u32 {
-        let mut res = self.rand();
-        for _ in 0..31 {
-            res ^= self.rand();
-        }
-        res
+    /// A tuple of the 624-word state array and the current index, suitable for [`Random::set_state`].
+    pub fn state(&self) -> ([u32; 624], usize) {
+        (self.mt, self.mti)
     }
 
-    /// Generates a random 32-bit unsigned integer using the Mersenne Twister algorithm.
+    /// Replaces the internal state array and index, for example to restore a state saved with
+    /// [`Random::state`] or [`Random::seed_swap`].
     ///
-    /// This method is the core function of the `Random` struct, providing the basic mechanism for generating random numbers.
+    /// # Arguments
+    /// * `mt` - The 624-word state array.
+    /// * `mti` - The index of the next word to use, at most `625`.
     ///
-    /// The `rand` method generates a random 32-bit number using the current state of the `mt` array.
-    /// It applies a series of bitwise transformations for tempering, which refines the output and improves
-    /// the statistical properties of the generated numbers.
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::with_seed(42);
+    /// let (mt, mti) = rng.state();
+    /// let first = rng.rand();
+    /// rng.set_state(mt, mti);
+    /// assert_eq!(rng.rand(), first);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `mti` is greater than 625.
+    ///
+    /// # Notes
+    /// - Any partial word left pending by [`Random::discard_bytes`] and any bits buffered by
+    ///   [`Random::bits_be`] or [`Random::bits_le`] are dropped.
+    pub fn set_state(&mut self, mt: [u32; 624], mti: usize) {
+        assert!(
+            mti <= UNSEEDED_MTI,
+            "mti must not exceed 625 for set_state"
+        );
+        self.mt = mt;
+        self.mti = mti;
+        self.pending_offset = 0;
+        self.bit_count = 0;
+    }
+
+    /// Creates a new instance of the `Random` struct, initializing the internal state for random number generation.
+    ///
+    /// This method seeds the random number generator with a default value obtained from the thread's random number generator.
+    ///
+    /// The `new` method initializes the `Random` struct. It sets the initial state of the `mt` array
+    /// using a default seed obtained from the system's RNG. This seeding process is crucial for ensuring
+    /// that each instance of `Random` produces a unique and unpredictable sequence of numbers.
     ///
     /// # Examples
     /// ```
     /// use vrd::random::Random;
-    /// let mut rng = Random::new();
-    /// let random_number = rng.rand(); // Generates a random 32-bit unsigned integer
+    /// let mut rng = Random::new(); // Creates a new instance of Random
+    /// let random_number = rng.rand(); // Generates a random number
     /// println!("Random number: {}", random_number);
     /// ```
     ///
     /// # Returns
-    /// A `u32` representing a randomly generated 32-bit unsigned integer.
+    /// A new instance of `Random` with its internal state initialized for random number generation.
+    pub fn new() -> Self {
+        let mut rng = Random::blank(MersenneTwisterParams::default());
+        rng.seed(rand::thread_rng().next_u32());
+        rng
+    }
+
+    /// Creates a new instance of the `Random` struct seeded with the canonical MT19937 default seed `5489`.
     ///
-    /// # Notes
-    /// - This method updates the internal state of the random number generator each time it is called.
-    /// - If the internal index (`mti`) reaches the threshold, it automatically reinitializes the internal state array.
-    pub fn rand(&mut self) -> u32 {
-        const N: usize = 624;
-        const M: usize = 397;
-        let config = MersenneTwisterConfig::<N, M>::default();
-        if self.mti >= N {
-            if self.mti == N + 1 {
-                self.seed(5489);
-            }
-            self.twist();
-        }
+    /// Unlike [`Random::new`], this constructor never touches an entropy source, so every generator it
+    /// returns produces the same sequence as the reference MT19937 implementation when no seed is given.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::unseeded();
+    /// assert_eq!(rng.rand(), 3499211612);
+    /// ```
+    ///
+    /// # Returns
+    /// A new instance of `Random` seeded with `5489`.
+    pub fn unseeded() -> Self {
+        Random::with_seed(DEFAULT_SEED)
+    }
 
-        let mut y = self.mt[self.mti];
-        self.mti += 1;
-        y ^= y >> 11;
-        y ^= (y << 7) & config.params.tempering_mask_b;
-        y ^= (y << 15) & config.params.tempering_mask_c;
-        y ^= y >> 18;
-        y
+    /// Creates a new instance of the `Random` struct seeded with the specified value.
+    ///
+    /// # Arguments
+    /// * `seed` - A `u32` value used to seed the generator.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng1 = Random::with_seed(42);
+    /// let mut rng2 = Random::with_seed(42);
+    /// assert_eq!(rng1.rand(), rng2.rand());
+    /// ```
+    ///
+    /// # Returns
+    /// A new instance of `Random` using the default parameters, seeded with `seed`.
+    pub fn with_seed(seed: u32) -> Self {
+        let mut rng = Random::blank(MersenneTwisterParams::default());
+        rng.seed(seed);
+        rng
     }
 
-    /// Generates a random 32-bit unsigned integer within a specified range.
+    /// Creates a new instance of the `Random` struct from a seed that may or may not be given.
+    ///
+    /// This is the usual branch behind an optional `--seed` flag: `Some(seed)` behaves exactly like
+    /// [`Random::with_seed`], and `None` falls back to [`Random::from_entropy`].
     ///
     /// # Arguments
-    /// * `min` - The lower bound of the range (inclusive).
-    /// * `max` - The upper bound of the range (exclusive).
+    /// * `seed` - The seed to use, or `None` to seed from the system's entropy source.
     ///
     /// # Examples
     /// ```
     /// use vrd::random::Random;
-    /// let mut rng = Random::new();
-    /// let random_number = rng.random_range(10, 20); // Generates a random number between 10 (inclusive) and 20 (exclusive)
-    /// println!("Random number between 10 and 20: {}", random_number);
+    /// let mut rng1 = Random::from_optional_seed(Some(42));
+    /// let mut rng2 = Random::with_seed(42);
+    /// assert_eq!(rng1.rand(), rng2.rand());
+    /// let mut rng3 = Random::from_optional_seed(None);
+    /// println!("Random number: {}", rng3.rand());
     /// ```
     ///
     /// # Returns
-    /// A `u32` representing a randomly generated number within the specified range.
+    /// A new instance of `Random` using the default parameters, seeded with `seed` when present and
+    /// with a non-deterministic value otherwise.
+    pub fn from_optional_seed(seed: Option<u32>) -> Self {
+        match seed {
+            Some(seed) => Random::with_seed(seed),
+            None => Random::from_entropy(),
+        }
+    }
+
+    /// Returns the first `count` outputs of a generator seeded with `seed`.
     ///
-    /// # Panics
-    /// Panics if `min` is not less than `max`.
-    pub fn random_range(&mut self, min: u32, max: u32) -> u32 {
-        assert!(
-            max > min,
-            "max must be greater than min for random_range"
-        );
-        let range = max - min;
-        min + (self.rand() % range)
+    /// This is the sequence produced by [`Random::with_seed`] followed by `count` calls to `rand()`, and
+    /// it is meant for pinning output in regression tests so that a refactor cannot silently change the
+    /// stream. With the default parameters the values match the reference MT19937 `init_genrand`; for
+    /// `seed = 42` they begin `1608637542, 3421126067, 4083286876, 787846414, 3143890026`, and for the
+    /// default seed `5489` they begin `3499211612, 581869302, 3890346734`.
+    ///
+    /// # Arguments
+    /// * `seed` - A `u32` value used to seed the generator.
+    /// * `count` - The number of outputs to return.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// assert_eq!(
+    ///     Random::test_vector(42, 3),
+    ///     vec![1608637542, 3421126067, 4083286876]
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    /// A `Vec<u32>` of the first `count` outputs.
+    pub fn test_vector(seed: u32, count: usize) -> Vec<u32> {
+        let mut rng = Random::with_seed(seed);
+        (0..count).map(|_| rng.rand()).collect()
     }
 
-    /// Generates a random number within a specified range of integer values.
+    /// Creates a new instance of the `Random` struct seeded from a human-readable label.
+    ///
+    /// The label is hashed with 64-bit FNV-1a and the eight little-endian hash bytes are passed to
+    /// [`Random::seed_from_bytes`]. The hash algorithm is pinned, so a label yields the same sequence in
+    /// every version of this crate and on every platform.
     ///
     /// # Arguments
-    /// * `min` - The lower bound of the range (inclusive).
-    /// * `max` - The upper bound of the range (inclusive).
+    /// * `label` - The label to derive the seed from, for example `"experiment-3"`.
     ///
     /// # Examples
     /// ```
     /// use vrd::random::Random;
-    /// let mut rng = Random::new();
-    /// let random_number = rng.range(1, 100); // Generates a random number between 1 and 100
-    /// println!("Random number between 1 and 100: {}", random_number);
+    /// let mut rng1 = Random::from_label("experiment-3");
+    /// let mut rng2 = Random::from_label("experiment-3");
+    /// assert_eq!(rng1.rand(), rng2.rand());
     /// ```
     ///
     /// # Returns
-    /// An `i32` representing a randomly generated number within the specified range.
+    /// A new instance of `Random` using the default parameters, seeded from `label`.
+    pub fn from_label(label: &str) -> Self {
+        let hash = fnv1a_64(label.as_bytes());
+        let mut rng = Random::blank(MersenneTwisterParams::default());
+        rng.seed_from_bytes(&hash.to_le_bytes());
+        rng
+    }
+
+    /// Creates a new instance of the `Random` struct seeded from any hashable value.
     ///
-    /// # Panics
-    /// Panics if `min` is greater than `max`.
-    pub fn range(&mut self, min: i32, max: i32) -> i32 {
-        assert!(
-            min <= max,
-            "min must be less than or equal to max for range"
-        );
-        self.int(min, max)
+    /// The value is fed to a 64-bit FNV-1a hasher and the eight little-endian hash bytes are passed to
+    /// [`Random::seed_from_bytes`], as [`Random::from_label`] does for strings. Unlike the randomly keyed
+    /// `DefaultHasher`, the hasher is pinned by this crate: it has no key, and integers are written in
+    /// little-endian order with `usize` widened to 64 bits, so the seed does not depend on the process,
+    /// the endianness or the pointer width.
+    ///
+    /// # Arguments
+    /// * `value` - The value to derive the seed from, for example a tuple of experiment parameters.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng1 = Random::from_hashable(&("run", 3u32));
+    /// let mut rng2 = Random::from_hashable(&("run", 3u32));
+    /// assert_eq!(rng1.rand(), rng2.rand());
+    /// ```
+    ///
+    /// # Notes
+    /// - The bytes hashed are whatever the value's `Hash` implementation writes. This crate keeps its
+    ///   hasher stable across versions, but the standard library does not promise that its own `Hash`
+    ///   implementations (for `str`, slices or tuples, say) never change, and neither may other crates.
+    ///   For seeds that must survive toolchain upgrades, prefer [`Random::from_label`] or
+    ///   [`Random::seed_from_bytes`] with bytes you encode yourself.
+    ///
+    /// # Returns
+    /// A new instance of `Random` using the default parameters, seeded from `value`.
+    pub fn from_hashable<H: Hash>(value: &H) -> Self {
+        let mut hasher = Fnv1aHasher(FNV_OFFSET_BASIS);
+        value.hash(&mut hasher);
+        let mut rng = Random::blank(MersenneTwisterParams::default());
+        rng.seed_from_bytes(&hasher.finish().to_le_bytes());
+        rng
     }
 
-    /// Seeds the random number generator with a specified value.
+    /// Creates a new instance of the `Random` struct seeded from another random number generator.
     ///
-    /// This method initializes the internal state array of the generator with a given seed, affecting the sequence of random numbers generated.
+    /// Enough bytes to cover the whole 624-word state are pulled from `rng` with `fill_bytes` and passed to
+    /// [`Random::seed_from_bytes`], so any `rand`-ecosystem generator, a CSPRNG say, can bootstrap this one.
+    /// This is the inherent, infallible counterpart of `SeedableRng::from_rng`.
     ///
-    /// The constant 1812433253u32 is used in the seeding process. It's derived from the fractional part
-    /// of the square root of 2. This particular value is chosen to provide good statistical properties
-    /// for the initial array of numbers.
+    /// # Arguments
+    /// * `rng` - The generator to draw the seed material from.
+    ///
+    /// # Examples
+    /// ```
+    /// use rand::rngs::mock::StepRng;
+    /// use vrd::random::Random;
+    /// let mut rng1 = Random::from_rng(&mut StepRng::new(1, 1));
+    /// let mut rng2 = Random::from_rng(&mut StepRng::new(1, 1));
+    /// assert_eq!(rng1.rand(), rng2.rand());
+    /// ```
+    ///
+    /// # Returns
+    /// A new instance of `Random` using the default parameters, seeded from the output of `rng`.
+    pub fn from_rng<R: RngCore>(rng: &mut R) -> Self {
+        let mut bytes = [0u8; 624 * 4];
+        rng.fill_bytes(&mut bytes);
+        let mut seeded =
+            Random::blank(MersenneTwisterParams::default());
+        seeded.seed_from_bytes(&bytes);
+        seeded
+    }
+
+    /// Creates a new instance of the `Random` struct with the specified algorithm parameters and seed.
+    ///
+    /// This pins down everything that determines the output sequence in a single call, which is useful for
+    /// research reproducibility.
     ///
     /// # Arguments
+    /// * `params` - The Mersenne Twister parameters to use for twisting and tempering.
     /// * `seed` - A `u32` value used to seed the generator.
     ///
     /// # Examples
     /// ```
+    /// use vrd::mersenne_twister::MersenneTwisterParams;
     /// use vrd::random::Random;
-    /// let mut rng = Random::new();
-    /// rng.seed(12345); // Seeds the random number generator
-    /// let random_number = rng.rand(); // Generates a random number based on the new seed
-    /// println!("Random number with seed 12345: {}", random_number);
+    /// let params = MersenneTwisterParams::default();
+    /// let mut rng = Random::with_params_and_seed(params, 42).unwrap();
+    /// println!("Random number: {}", rng.rand());
     /// ```
     ///
-    /// # Notes
-    /// - Seeding the generator is essential for reproducibility of the random number sequence.
-    pub fn seed(&mut self, seed: u32) {
-        const N: usize = 624;
-        self.mt[0] = seed;
-        for i in 1..N {
-            self.mt[i] = 1812433253u32
-                .wrapping_mul(self.mt[i - 1] ^ (self.mt[i - 1] >> 30))
-                .wrapping_add(i as u32);
-        }
-        self.mti = N;
+    /// # Returns
+    /// A `Result` containing the new `Random` instance.
+    ///
+    /// # Errors
+    /// Returns a `VrdError::GeneralError` if `params` fails `MersenneTwisterConfig::validate`.
+    pub fn with_params_and_seed(
+        params: MersenneTwisterParams,
+        seed: u32,
+    ) -> Result<Self, VrdError> {
+        MersenneTwisterConfig::<624, 397>::validate(&params)?;
+        let mut rng = Random::blank(params);
+        rng.seed(seed);
+        Ok(rng)
     }
 
-    /// Performs the "twisting" operation to update the internal state array of the random number generator.
+    /// Creates a new instance of the `Random` struct from a configuration and a seed.
     ///
-    /// This method is a key part of the Mersenne Twister algorithm, and it's called internally when the generator's index exceeds its predefined threshold.
+    /// The configuration's const parameters must match the generator's 624-word state and shift of 397;
+    /// any other `N` or `M` is rejected with a message giving the expected and actual value.
     ///
-    /// The `twist` method is a key part of the Mersenne Twister algorithm. It generates a new array of
-    /// 624 numbers based on the current array. This method uses bitwise operations and modular arithmetic
-    /// to transform the existing numbers into a new set, thereby 'twisting' the current state. This is
-    /// essential for maintaining the algorithm's long period and high-quality randomness.
+    /// # Arguments
+    /// * `config` - The Mersenne Twister configuration to use.
+    /// * `seed` - A `u32` value used to seed the generator.
     ///
     /// # Examples
     /// ```
     /// use vrd::random::Random;
-    /// let mut rng = Random::new();
-    /// rng.twist(); // Manually performs a twist operation
+    /// use vrd::MersenneTwisterConfig;
+    /// let mut rng = Random::from_config(&MersenneTwisterConfig::default(), 42).unwrap();
+    /// assert_eq!(rng.rand(), 1608637542);
     /// ```
     ///
-    /// # Notes
-    /// - This method modifies the internal state array, ensuring that future random numbers generated are different from the previous ones.
-    pub fn twist(&mut self) {
-        const N: usize = 624;
-        const M: usize = 397;
-        let config = MersenneTwisterConfig::<N, M>::default();
-        for i in 0..N {
-            let x = (self.mt[i] & config.params.upper_mask)
-                + (self.mt[(i + 1) % N] & config.params.lower_mask);
-            let x_a = x >> 1;
-            self.mt[i] = if x % 2 != 0 {
-                self.mt[(i + M) % N] ^ x_a ^ config.params.matrix_a
-            } else {
-                self.mt[(i + M) % N] ^ x_a
-            };
+    /// # Returns
+    /// A `Result` containing the new `Random` instance.
+    ///
+    /// # Errors
+    /// Returns a `VrdError::GeneralError` if `N` is not 624, `M` is not 397, or the parameters fail
+    /// `MersenneTwisterConfig::validate`.
+    pub fn from_config<const N: usize, const M: usize>(
+        config: &MersenneTwisterConfig<N, M>,
+        seed: u32,
+    ) -> Result<Self, VrdError> {
+        config.validate_dimensions(624, 397)?;
+        Random::with_params_and_seed(config.params, seed)
+    }
+
+    /// Returns the Mersenne Twister configuration used by this generator.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// use vrd::MersenneTwisterConfig;
+    /// let rng = Random::with_seed(42);
+    /// assert_eq!(rng.config(), MersenneTwisterConfig::default());
+    /// ```
+    ///
+    /// # Returns
+    /// A `MersenneTwisterConfig<624, 397>` holding the parameters used for twisting and tempering.
+    pub fn config(&self) -> MersenneTwisterConfig<624, 397> {
+        MersenneTwisterConfig {
+            params: self.params,
         }
-        self.mti = 0;
     }
 
-    /// Generates a random 64-bit signed integer.
+    /// Returns whether this generator uses the default MT19937 parameters.
     ///
     /// # Examples
     /// ```
     /// use vrd::random::Random;
-    /// let mut rng = Random::new();
-    /// let random_i64 = rng.i64();
-    /// println!("Random i64: {}", random_i64);
+    /// let rng = Random::with_seed(42);
+    /// assert!(rng.uses_default_config());
     /// ```
     ///
     /// # Returns
-    /// An `i64` representing a randomly generated 64-bit signed integer.
-    pub fn i64(&mut self) -> i64 {
-        let high = self.rand() as i64;
-        let low = self.rand() as i64;
-        (high << 32) | low
+    /// `true` if the parameters equal `MersenneTwisterParams::default()`, `false` otherwise.
+    pub fn uses_default_config(&self) -> bool {
+        self.params == MersenneTwisterParams::default()
     }
 
-    /// Generates a random 64-bit unsigned integer.
+    /// Creates a family of generators from a list of seeds using the C++ `std::seed_seq` mixing algorithm.
+    ///
+    /// The input seeds are expanded into `624 * count` well-mixed words, and each generator takes its
+    /// 624-word state directly from a disjoint slice of that output. This makes the generators
+    /// independent of each other while keeping the whole family reproducible from the same input seeds.
+    ///
+    /// # Arguments
+    /// * `seeds` - The seed material. An empty slice is allowed.
+    /// * `count` - The number of generators to create.
     ///
     /// # Examples
     /// ```
     /// use vrd::random::Random;
-    /// let mut rng = Random::new();
-    /// let random_u64 = rng.u64();
-    /// println!("Random u64: {}", random_u64);
+    /// let mut family = Random::seed_sequence(&[1, 2, 3], 4);
+    /// assert_eq!(family.len(), 4);
+    /// println!("First worker draw: {}", family[0].rand());
     /// ```
     ///
     /// # Returns
-    /// A `u64` representing a randomly generated 64-bit unsigned integer.
-    pub fn u64(&mut self) -> u64 {
-        let high = self.rand() as u64;
-        let low = self.rand() as u64;
-        (high << 32) | low
+    /// A `Vec<Random>` containing `count` generators using the default parameters.
+    pub fn seed_sequence(seeds: &[u32], count: usize) -> Vec<Random> {
+        const N: usize = 624;
+        let mut words = vec![0u32; N * count];
+        seed_seq_generate(seeds, &mut words);
+        words
+            .chunks(N)
+            .map(|state| {
+                let mut rng =
+                    Random::blank(MersenneTwisterParams::default());
+                rng.mt.copy_from_slice(state);
+                if rng.mt.iter().all(|&word| word == 0) {
+                    rng.mt[0] = 0x80000000;
+                }
+                rng.mti = N;
+                rng
+            })
+            .collect()
+    }
+
+    /// Fills whole groups of `TEMPER_LANES` words directly from the state array and returns the unfilled tail.
+    ///
+    /// The tempering runs four words per vector register through [`temper_lanes`]. Words are consumed in
+    /// exactly the same order as repeated `rand()` calls.
+    #[cfg(all(
+        feature = "simd",
+        any(target_arch = "x86_64", target_arch = "aarch64")
+    ))]
+    fn fill_bytes_lanes<'a>(
+        &mut self,
+        mut dest: &'a mut [u8],
+    ) -> &'a mut [u8] {
+        const N: usize = 624;
+        const BLOCK: usize = TEMPER_LANES * 4;
+        while dest.len() >= BLOCK {
+            if self.mti >= N {
+                if self.is_uninitialized() {
+                    self.seed(DEFAULT_SEED);
+                }
+                self.twist();
+            }
+            let groups =
+                ((N - self.mti) / TEMPER_LANES).min(dest.len() / BLOCK);
+            if groups == 0 {
+                // Fewer than a full group of words remains before the next twist.
+                let (head, tail) =
+                    std::mem::take(&mut dest).split_at_mut(4);
+                head.copy_from_slice(&self.rand().to_le_bytes());
+                dest = tail;
+                continue;
+            }
+            let (head, tail) =
+                std::mem::take(&mut dest).split_at_mut(groups * BLOCK);
+            for chunk in head.chunks_exact_mut(BLOCK) {
+                let mut lanes = [0u32; TEMPER_LANES];
+                lanes.copy_from_slice(
+                    &self.mt[self.mti..self.mti + TEMPER_LANES],
+                );
+                self.mti += TEMPER_LANES;
+                #[cfg(feature = "counters")]
+                {
+                    self.draws += TEMPER_LANES as u64;
+                }
+                temper_lanes(&mut lanes, self.params);
+                #[cfg(feature = "counters")]
+                {
+                    self.last = Some(lanes[TEMPER_LANES - 1]);
+                }
+                for (bytes, word) in
+                    chunk.chunks_exact_mut(4).zip(lanes.iter())
+                {
+                    bytes.copy_from_slice(&word.to_le_bytes());
+                }
+            }
+            dest = tail;
+        }
+        dest
+    }
+
+    /// Creates an uninitialized generator with an all-zero state array and the given parameters.
+    fn blank(params: MersenneTwisterParams) -> Self {
+        const N: usize = 624;
+        Random {
+            mt: [0; N],
+            mti: UNSEEDED_MTI,
+            params,
+            pending_word: 0,
+            pending_offset: 0,
+            bit_word: 0,
+            bit_count: 0,
+            #[cfg(feature = "counters")]
+            draws: 0,
+            #[cfg(feature = "counters")]
+            last: None,
+        }
+    }
+
+    /// Advances past `n` state words without tempering them, twisting as blocks are used up.
+    fn skip_words(&mut self, n: usize) {
+        const N: usize = 624;
+        let mut n = n;
+        while n > 0 {
+            if self.mti >= N {
+                if self.is_uninitialized() {
+                    self.seed(DEFAULT_SEED);
+                }
+                self.twist();
+            }
+            let step = n.min(N - self.mti);
+            self.mti += step;
+            n -= step;
+        }
+    }
+
+    /// Returns `true` if the generator has never been seeded.
+    ///
+    /// Only `mti == 625`, the sentinel set by the internal blank constructor, counts as uninitialized. Any
+    /// other index of `624` or more simply means the current block is used up, so the next draw twists the
+    /// existing state without reseeding it.
+    fn is_uninitialized(&self) -> bool {
+        self.mti == UNSEEDED_MTI
+    }
+
+    /// Generates a pseudo-random number by combining multiple random number generations.
+    ///
+    /// This method enhances the randomness by XOR-ing multiple calls to the basic random number generator.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let pseudo_random_number = rng.pseudo(); // Generates a pseudo-random number
+    /// println!("Pseudo-random number: {}", pseudo_random_number);
+    /// ```
+    ///
+    /// # Returns
+    /// A `u32` representing a pseudo-random number generated by combining multiple random number generations.
+    pub fn pseudo(&mut self) -> u32 {
+        let mut res = self.rand();
+        for _ in 0..31 {
+            res ^= self.rand();
+        }
+        res
+    }
+
+    /// Generates a random 32-bit unsigned integer using the Mersenne Twister algorithm.
+    ///
+    /// This method is the core function of the `Random` struct, providing the basic mechanism for generating random numbers.
+    ///
+    /// The `rand` method generates a random 32-bit number using the current state of the `mt` array.
+    /// It applies a series of bitwise transformations for tempering, which refines the output and improves
+    /// the statistical properties of the generated numbers.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let random_number = rng.rand(); // Generates a random 32-bit unsigned integer
+    /// println!("Random number: {}", random_number);
+    /// ```
+    ///
+    /// # Returns
+    /// A `u32` representing a randomly generated 32-bit unsigned integer.
+    ///
+    /// # Notes
+    /// - This method updates the internal state of the random number generator each time it is called.
+    /// - If the internal index (`mti`) is `624` or more, the state array is twisted before drawing. An index
+    ///   of exactly `625` marks a generator that was never seeded, which is seeded with `5489` first.
+    /// - This method never panics, whatever value the public `mti` field holds.
+    pub fn rand(&mut self) -> u32 {
+        const N: usize = 624;
+        if self.mti >= N {
+            if self.is_uninitialized() {
+                self.seed(DEFAULT_SEED);
+            }
+            self.twist();
+        }
+
+        let y = self.mt[self.mti];
+        self.mti += 1;
+        #[cfg(feature = "counters")]
+        {
+            self.draws += 1;
+        }
+        let output = Random::temper(y, &self.params);
+        #[cfg(feature = "counters")]
+        {
+            self.last = Some(output);
+        }
+        output
+    }
+
+    /// Applies the Mersenne Twister tempering transform to a raw state word.
+    ///
+    /// Tempering is the output step of `rand()`: it scrambles the bits of a state word so that the outputs
+    /// equidistribute well, using the shift amounts of MT19937 and the tempering masks from `params`. The
+    /// transform is a bijection on `u32`, and `untemper` is its inverse.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::with_seed(42);
+    /// let first = rng.rand();
+    /// assert_eq!(Random::temper(rng.mt[0], &rng.params), first);
+    /// ```
+    ///
+    /// # Returns
+    /// The tempered `u32` that `rand()` would return for the state word `y`.
+    pub fn temper(y: u32, params: &MersenneTwisterParams) -> u32 {
+        let mut y = y;
+        y ^= y >> 11;
+        y ^= (y << 7) & params.tempering_mask_b;
+        y ^= (y << 15) & params.tempering_mask_c;
+        y ^= y >> 18;
+        y
+    }
+
+    /// Inverts the Mersenne Twister tempering transform.
+    ///
+    /// Given an output of `rand()`, `untemper` recovers the state word it was produced from. Collecting 624
+    /// consecutive outputs and untempering them reconstructs the full state, which is why Mersenne Twister
+    /// output must never be relied on where unpredictability matters.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::mersenne_twister::MersenneTwisterParams;
+    /// use vrd::random::Random;
+    /// let params = MersenneTwisterParams::default();
+    /// let tempered = Random::temper(0xdead_beef, &params);
+    /// assert_eq!(Random::untemper(tempered, &params), 0xdead_beef);
+    /// ```
+    ///
+    /// # Returns
+    /// The state word `x` for which `temper(x, params) == y`.
+    pub fn untemper(y: u32, params: &MersenneTwisterParams) -> u32 {
+        let mut y = y;
+        y = undo_right_shift_xor(y, 18);
+        y = undo_left_shift_xor(y, 15, params.tempering_mask_c);
+        y = undo_left_shift_xor(y, 7, params.tempering_mask_b);
+        undo_right_shift_xor(y, 11)
+    }
+
+    /// Returns the number of raw outputs this generator has drawn from its state array.
+    ///
+    /// Every `rand()` call counts as one draw, so higher-level methods count the words they consume (two
+    /// for `u64()`, for example). The counter is not reset by seeding.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::with_seed(42);
+    /// let _ = rng.rand();
+    /// let _ = rng.u64();
+    /// assert_eq!(rng.draw_count(), 3);
+    /// ```
+    ///
+    /// # Notes
+    /// - Only available with the `counters` feature, so the default `rand()` path is unaffected.
+    /// - The counter is part of the generator state when enabled: it is included in the serialized form
+    ///   and in the derived comparisons, so two generators with the same `mt` and `mti` but different
+    ///   histories compare as unequal.
+    #[cfg(feature = "counters")]
+    pub fn draw_count(&self) -> u64 {
+        self.draws
+    }
+
+    /// Returns the most recent value returned by `rand()`, without advancing the generator.
+    ///
+    /// This is `None` until the first draw. Methods that build on `rand()` record their final word, so
+    /// after `u64()` it holds the low half of the returned value.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::with_seed(42);
+    /// assert_eq!(rng.last_output(), None);
+    /// let value = rng.rand();
+    /// assert_eq!(rng.last_output(), Some(value));
+    /// ```
+    ///
+    /// # Notes
+    /// - Only available with the `counters` feature, so the default `rand()` path is unaffected.
+    /// - Like the draw counter, the value is kept across seeding and is part of the serialized form and
+    ///   the derived comparisons.
+    #[cfg(feature = "counters")]
+    pub fn last_output(&self) -> Option<u32> {
+        self.last
+    }
+
+    /// Generates a random 32-bit unsigned integer, failing instead of auto-seeding an uninitialized generator.
+    ///
+    /// [`Random::rand`] silently seeds a generator that was never seeded with `5489`, which can hide a
+    /// missing `seed` call. This method returns an error in that case and otherwise behaves exactly like
+    /// `rand()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::with_seed(42);
+    /// assert!(rng.next_u32_checked().is_ok());
+    /// ```
+    ///
+    /// # Returns
+    /// A `Result` containing the next output of the generator.
+    ///
+    /// # Errors
+    /// Returns a `VrdError::GeneralError` if the generator is in the uninitialized state, that is `mti` is
+    /// 625 and the state array is all zeros.
+    pub fn next_u32_checked(&mut self) -> Result<u32, VrdError> {
+        if self.is_uninitialized()
+            && self.mt.iter().all(|&word| word == 0)
+        {
+            return Err(VrdError::GeneralError(
+                "Generator has not been seeded".to_string(),
+            ));
+        }
+        Ok(self.rand())
+    }
+
+    /// Generates a fixed-size array of random 32-bit unsigned integers.
+    ///
+    /// The array is filled on the stack from successive `rand()` calls, so no heap allocation takes place.
+    /// This is useful for fixed-size blocks such as the 16 words of a hash input.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let block: [u32; 16] = rng.draw_many();
+    /// println!("Random block: {:?}", block);
+    /// ```
+    ///
+    /// # Returns
+    /// A `[u32; K]` containing `K` consecutive outputs of the generator.
+    pub fn draw_many<const K: usize>(&mut self) -> [u32; K] {
+        let mut words = [0u32; K];
+        for word in words.iter_mut() {
+            *word = self.rand();
+        }
+        words
+    }
+
+    /// Generates a random 32-bit unsigned integer within a specified range.
+    ///
+    /// # Arguments
+    /// * `min` - The lower bound of the range (inclusive).
+    /// * `max` - The upper bound of the range (exclusive).
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let random_number = rng.random_range(10, 20); // Generates a random number between 10 (inclusive) and 20 (exclusive)
+    /// println!("Random number between 10 and 20: {}", random_number);
+    /// ```
+    ///
+    /// # Returns
+    /// A `u32` representing a randomly generated number within the specified range.
+    ///
+    /// # Panics
+    /// Panics if `min` is not less than `max`.
+    pub fn random_range(&mut self, min: u32, max: u32) -> u32 {
+        assert!(
+            max > min,
+            "max must be greater than min for random_range"
+        );
+        let range = max - min;
+        min + (self.rand() % range)
+    }
+
+    /// Generates a random 32-bit unsigned integer within an inclusive range, without modulo bias.
+    ///
+    /// This is the inclusive companion of [`Random::random_range`]: because `max` is included, the range
+    /// can reach `u32::MAX`. Draws that would bias the result are rejected and redrawn, as in
+    /// [`Random::gen_int`].
+    ///
+    /// # Arguments
+    /// * `min` - The lower bound of the range (inclusive).
+    /// * `max` - The upper bound of the range (inclusive).
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let value = rng.random_range_inclusive(u32::MAX - 1, u32::MAX);
+    /// assert!(value >= u32::MAX - 1);
+    /// ```
+    ///
+    /// # Returns
+    /// A `u32` between `min` and `max`, inclusive.
+    ///
+    /// # Panics
+    /// Panics if `min` is greater than `max`.
+    pub fn random_range_inclusive(
+        &mut self,
+        min: u32,
+        max: u32,
+    ) -> u32 {
+        assert!(
+            min <= max,
+            "min must be less than or equal to max for random_range_inclusive"
+        );
+        self.gen_int(min, max)
+    }
+
+    /// Maps an existing 32-bit value into a specified range without drawing from the generator.
+    ///
+    /// This is useful for bounding values obtained elsewhere, such as a stored `rand()` stream, using Lemire's
+    /// multiply-shift reduction: `min + ((value * (max - min)) >> 32)`. The bounds follow the same convention
+    /// as [`Random::random_range`].
+    ///
+    /// # Arguments
+    /// * `value` - The 32-bit value to map.
+    /// * `min` - The lower bound of the range (inclusive).
+    /// * `max` - The upper bound of the range (exclusive).
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let value = rng.rand();
+    /// let mapped = Random::map_to_range(value, 10, 20);
+    /// assert!((10..20).contains(&mapped));
+    /// ```
+    ///
+    /// # Returns
+    /// A `u32` in the range `[min, max)`.
+    ///
+    /// # Panics
+    /// Panics if `min` is not less than `max`.
+    ///
+    /// # Notes
+    /// - Because a single input value cannot be rejected and redrawn, ranges whose length is not a power of two
+    ///   carry a bias of at most `(max - min) / 2^32` per output. This is negligible for small ranges.
+    pub fn map_to_range(value: u32, min: u32, max: u32) -> u32 {
+        assert!(
+            max > min,
+            "max must be greater than min for map_to_range"
+        );
+        let range = u64::from(max - min);
+        min + ((u64::from(value) * range) >> 32) as u32
+    }
+
+    /// Generates a random number within a specified range of integer values.
+    ///
+    /// # Arguments
+    /// * `min` - The lower bound of the range (inclusive).
+    /// * `max` - The upper bound of the range (inclusive).
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let random_number = rng.range(1, 100); // Generates a random number between 1 and 100
+    /// println!("Random number between 1 and 100: {}", random_number);
+    /// ```
+    ///
+    /// # Returns
+    /// An `i32` representing a randomly generated number within the specified range.
+    ///
+    /// # Panics
+    /// Panics if `min` is greater than `max`.
+    ///
+    /// # Notes
+    /// - The full span `range(i32::MIN, i32::MAX)` is supported and returns every `i32` with equal probability.
+    pub fn range(&mut self, min: i32, max: i32) -> i32 {
+        assert!(
+            min <= max,
+            "min must be less than or equal to max for range"
+        );
+        self.int(min, max)
+    }
+
+    /// Seeds the random number generator with a specified value.
+    ///
+    /// This method initializes the internal state array of the generator with a given seed, affecting the sequence of random numbers generated.
+    ///
+    /// The constant 1812433253u32 is used in the seeding process. It's derived from the fractional part
+    /// of the square root of 2. This particular value is chosen to provide good statistical properties
+    /// for the initial array of numbers.
+    ///
+    /// # Arguments
+    /// * `seed` - A `u32` value used to seed the generator.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// rng.seed(12345); // Seeds the random number generator
+    /// let random_number = rng.rand(); // Generates a random number based on the new seed
+    /// println!("Random number with seed 12345: {}", random_number);
+    /// ```
+    ///
+    /// # Notes
+    /// - Seeding the generator is essential for reproducibility of the random number sequence.
+    /// - The initialization uses the wrapping arithmetic of the reference `init_genrand`, and
+    ///   [`Random::new`] and [`Random::with_seed`] both seed through this method, so every construction
+    ///   path yields the canonical MT19937 state for a given seed.
+    pub fn seed(&mut self, seed: u32) {
+        const N: usize = 624;
+        self.mt[0] = seed;
+        for i in 1..N {
+            self.mt[i] = 1812433253u32
+                .wrapping_mul(self.mt[i - 1] ^ (self.mt[i - 1] >> 30))
+                .wrapping_add(i as u32);
+        }
+        self.mti = N;
+        self.pending_offset = 0;
+        self.bit_count = 0;
+    }
+
+    /// Seeds the random number generator and verifies that the resulting state is not degenerate.
+    ///
+    /// Mersenne Twister only uses the top bit of `mt[0]`, so a state whose top bit of `mt[0]` and every
+    /// other word are zero is a fixed point: it twists to itself and outputs zero forever. Seeding with
+    /// [`Random::seed`] never produces that state, since `mt[i]` always has `i` added in, which leaves
+    /// `mt[2]` or a later word nonzero, so `checked_seed` returns `Ok` for every `u32` seed. The check
+    /// guards against future seeding paths that could break that guarantee.
+    ///
+    /// # Arguments
+    /// * `seed` - A `u32` value used to seed the generator.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// rng.checked_seed(0).unwrap();
+    /// assert_eq!(rng.rand(), 2357136044);
+    /// ```
+    ///
+    /// # Returns
+    /// `Ok(())` once the generator has been seeded.
+    ///
+    /// # Errors
+    /// Returns a `VrdError::GeneralError` if seeding left the generator in the all-zero state.
+    pub fn checked_seed(&mut self, seed: u32) -> Result<(), VrdError> {
+        self.seed(seed);
+        let upper_mask = self.params.upper_mask;
+        if self.mt[0] & upper_mask == 0
+            && self.mt[1..].iter().all(|&word| word == 0)
+        {
+            return Err(VrdError::GeneralError(
+                "Seeding produced the all-zero state".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Seeds the random number generator and returns the state it had before.
+    ///
+    /// This supports the "temporarily reseed, do some work, restore" pattern: pass the returned state to
+    /// [`Random::set_state`] to resume the original sequence exactly where it left off.
+    ///
+    /// # Arguments
+    /// * `seed` - A `u32` value used to seed the generator.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::with_seed(42);
+    /// let (mt, mti) = rng.seed_swap(7);
+    /// let _ = rng.rand(); // drawn from the seed-7 sequence
+    /// rng.set_state(mt, mti);
+    /// assert_eq!(rng.rand(), Random::with_seed(42).rand());
+    /// ```
+    ///
+    /// # Returns
+    /// The previous state array and index, as returned by [`Random::state`].
+    pub fn seed_swap(&mut self, seed: u32) -> ([u32; 624], usize) {
+        let previous = self.state();
+        self.seed(seed);
+        previous
+    }
+
+    /// Seeds the random number generator from an arbitrary sequence of bytes.
+    ///
+    /// The bytes are packed into little-endian 32-bit words (the final word is zero-padded) and mixed into the
+    /// whole state array using the reference MT19937 `init_by_array` procedure, so every input byte affects
+    /// the resulting sequence.
+    ///
+    /// # Arguments
+    /// * `bytes` - The seed material. An empty slice is treated as a single zero word.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng1 = Random::new();
+    /// let mut rng2 = Random::new();
+    /// rng1.seed_from_bytes(b"experiment-3");
+    /// rng2.seed_from_bytes(b"experiment-3");
+    /// assert_eq!(rng1.rand(), rng2.rand());
+    /// ```
+    pub fn seed_from_bytes(&mut self, bytes: &[u8]) {
+        let mut key: Vec<u32> = bytes
+            .chunks(4)
+            .map(|chunk| {
+                let mut word = [0u8; 4];
+                word[..chunk.len()].copy_from_slice(chunk);
+                u32::from_le_bytes(word)
+            })
+            .collect();
+        if key.is_empty() {
+            key.push(0);
+        }
+        self.init_by_array(&key);
+    }
+
+    /// Seeds the random number generator by mixing several seed sources into the whole state array.
+    ///
+    /// All sources (for example a process ID, a timestamp and a counter) are passed as one key to the
+    /// reference MT19937 `init_by_array` procedure, so every source affects the whole state and their order
+    /// matters. Unlike [`Random::seed_sequence`], which derives a family of independent generators, this
+    /// reseeds a single generator in place and matches the reference `init_by_array` output exactly.
+    ///
+    /// # Arguments
+    /// * `sources` - The seed words to combine. An empty slice is treated as a single zero word.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// rng.seed_from_sources(&[0x123, 0x234, 0x345, 0x456]);
+    /// assert_eq!(rng.rand(), 1067595299);
+    /// ```
+    pub fn seed_from_sources(&mut self, sources: &[u32]) {
+        if sources.is_empty() {
+            self.init_by_array(&[0]);
+        } else {
+            self.init_by_array(sources);
+        }
+    }
+
+    /// Creates a new generator seeded from a fixed four-word key with the reference `init_by_array`.
+    ///
+    /// This is the seeding used by the reference `mt19937ar.c` test program, and by C++ and Python code
+    /// that seeds MT19937 from a four-word array, so the outputs match those implementations exactly. It
+    /// is equivalent to [`Random::seed_from_sources`] with the same four words.
+    ///
+    /// # Arguments
+    /// * `key` - The four seed words.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::from_key([0x123, 0x234, 0x345, 0x456]);
+    /// assert_eq!(rng.rand(), 1067595299);
+    /// ```
+    ///
+    /// # Returns
+    /// A new instance of `Random` seeded from `key`.
+    pub fn from_key(key: [u32; 4]) -> Random {
+        let mut rng = Random::blank(MersenneTwisterParams::default());
+        rng.init_by_array(&key);
+        rng
+    }
+
+    /// Reseeds this generator from the output of another generator.
+    ///
+    /// Eight words are drawn from `source` and passed to [`Random::seed_from_bytes`]. The derivation is fully
+    /// reproducible: given the states of both generators, the reseeded state is always the same, and `source`
+    /// always advances by exactly eight draws.
+    ///
+    /// # Arguments
+    /// * `source` - The generator whose output is used as seed material.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut source = Random::with_seed(42);
+    /// let mut child = Random::new();
+    /// child.reseed_from(&mut source);
+    /// println!("Derived number: {}", child.rand());
+    /// ```
+    pub fn reseed_from(&mut self, source: &mut Random) {
+        const RESEED_WORDS: usize = 8;
+        let mut bytes = Vec::with_capacity(RESEED_WORDS * 4);
+        for _ in 0..RESEED_WORDS {
+            bytes.extend_from_slice(&source.rand().to_le_bytes());
+        }
+        self.seed_from_bytes(&bytes);
+    }
+
+    /// Derives a named substream from this generator without advancing it.
+    ///
+    /// The current state array and index are combined with the bytes of `label` and passed to
+    /// [`Random::seed_from_bytes`] on a new generator using the same parameters. The same master state and
+    /// label always yield the same substream, so a simulation can give each component (for example
+    /// `"arrivals"` and `"service-times"`) its own reproducible stream from a single master seed.
+    ///
+    /// # Arguments
+    /// * `label` - The name of the substream.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let master = Random::with_seed(42);
+    /// let mut arrivals = master.substream("arrivals");
+    /// let mut again = master.substream("arrivals");
+    /// assert_eq!(arrivals.rand(), again.rand());
+    /// ```
+    ///
+    /// # Returns
+    /// A new `Random` instance seeded from the master state and `label`.
+    pub fn substream(&self, label: &str) -> Random {
+        let mut bytes =
+            Vec::with_capacity((self.mt.len() + 1) * 4 + label.len());
+        for word in self.mt.iter() {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        bytes.extend_from_slice(&(self.mti as u32).to_le_bytes());
+        bytes.extend_from_slice(label.as_bytes());
+        let mut child = Random::blank(self.params);
+        child.seed_from_bytes(&bytes);
+        child
+    }
+
+    /// Splits a generator's stream into `parts` interleaved subsequences using leapfrog decomposition.
+    ///
+    /// Generator `i` of the result produces outputs `i`, `i + parts`, `i + 2 * parts`, ... of the master's
+    /// stream, so drawing once from each generator in turn reproduces the master sequence exactly. Each
+    /// generator starts as a copy of `master` and then skips `parts - 1` state words after every output,
+    /// which makes every draw roughly `parts` times as expensive as a plain draw.
+    ///
+    /// # Arguments
+    /// * `master` - The generator whose stream is split. It is not advanced.
+    /// * `parts` - The number of generators to produce.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut master = Random::with_seed(42);
+    /// let mut parts = Random::leapfrog(&master, 2);
+    /// assert_eq!(parts[0].rand(), master.rand());
+    /// assert_eq!(parts[1].rand(), master.rand());
+    /// assert_eq!(parts[0].rand(), master.rand());
+    /// ```
+    ///
+    /// # Returns
+    /// A vector of `parts` [`Leapfrog`] generators, where generator `i` starts at offset `i` of the master
+    /// stream. The parts are `Leapfrog` rather than plain `Random` values so that the stride travels with
+    /// them; `Leapfrog` implements `RngCore`, so each part can be passed wherever a `rand` generator is
+    /// expected.
+    ///
+    /// # Panics
+    /// Panics if `parts` is zero, or if the combined stride does not fit in a `usize`.
+    ///
+    /// # Notes
+    /// - A master with `mti == 625` is seeded with the default seed first, as `rand()` would do.
+    /// - Use [`Leapfrog::leapfrog`] to split one of the resulting generators again.
+    pub fn leapfrog(master: &Random, parts: usize) -> Vec<Leapfrog> {
+        Leapfrog::split(master, 0, parts)
+    }
+
+    /// Initializes the state array from a key using the reference MT19937 `init_by_array` algorithm.
+    fn init_by_array(&mut self, key: &[u32]) {
+        const N: usize = 624;
+        self.seed(19650218);
+        let mut i = 1;
+        let mut j = 0;
+        for _ in 0..N.max(key.len()) {
+            self.mt[i] = (self.mt[i]
+                ^ (self.mt[i - 1] ^ (self.mt[i - 1] >> 30))
+                    .wrapping_mul(1664525))
+            .wrapping_add(key[j])
+            .wrapping_add(j as u32);
+            i += 1;
+            j += 1;
+            if i >= N {
+                self.mt[0] = self.mt[N - 1];
+                i = 1;
+            }
+            if j >= key.len() {
+                j = 0;
+            }
+        }
+        for _ in 0..N - 1 {
+            self.mt[i] = (self.mt[i]
+                ^ (self.mt[i - 1] ^ (self.mt[i - 1] >> 30))
+                    .wrapping_mul(1566083941))
+            .wrapping_sub(i as u32);
+            i += 1;
+            if i >= N {
+                self.mt[0] = self.mt[N - 1];
+                i = 1;
+            }
+        }
+        // Guarantee a non-zero initial state.
+        self.mt[0] = 0x80000000;
+        self.mti = N;
+    }
+
+    /// Performs the "twisting" operation to update the internal state array of the random number generator.
+    ///
+    /// This method is a key part of the Mersenne Twister algorithm, and it's called internally when the generator's index exceeds its predefined threshold.
+    ///
+    /// The `twist` method is a key part of the Mersenne Twister algorithm. It generates a new array of
+    /// 624 numbers based on the current array. This method uses bitwise operations and modular arithmetic
+    /// to transform the existing numbers into a new set, thereby 'twisting' the current state. This is
+    /// essential for maintaining the algorithm's long period and high-quality randomness.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// rng.twist(); // Manually performs a twist operation
+    /// ```
+    ///
+    /// # Notes
+    /// - This method modifies the internal state array, ensuring that future random numbers generated are different from the previous ones.
+    pub fn twist(&mut self) {
+        const N: usize = 624;
+        const M: usize = 397;
+        let params = self.params;
+        for i in 0..N {
+            let x = (self.mt[i] & params.upper_mask)
+                + (self.mt[(i + 1) % N] & params.lower_mask);
+            let x_a = x >> 1;
+            self.mt[i] = if x % 2 != 0 {
+                self.mt[(i + M) % N] ^ x_a ^ params.matrix_a
+            } else {
+                self.mt[(i + M) % N] ^ x_a
+            };
+        }
+        self.mti = 0;
+    }
+
+    /// Advances the generator to the next twist boundary, leaving it at `mti == 0`.
+    ///
+    /// The values remaining in the current block are discarded and the state array is twisted, so the
+    /// next `rand()` returns the first value of a fresh block. This is a convenient alignment point for
+    /// checkpoints. A generator that is already at `mti == 0` is left unchanged, and one that was never
+    /// seeded is first seeded with the default seed.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::with_seed(42);
+    /// let _ = rng.rand();
+    /// let skipped = rng.advance_to_twist_boundary();
+    /// assert_eq!(skipped, 623);
+    /// assert_eq!(rng.mti(), 0);
+    /// ```
+    ///
+    /// # Returns
+    /// The number of values that were skipped to reach the boundary.
+    pub fn advance_to_twist_boundary(&mut self) -> usize {
+        const N: usize = 624;
+        if self.mti == 0 {
+            return 0;
+        }
+        if self.is_uninitialized() {
+            self.seed(DEFAULT_SEED);
+        }
+        let skipped = N - self.mti.min(N);
+        self.twist();
+        skipped
+    }
+
+    /// Generates a random 64-bit signed integer.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let random_i64 = rng.i64();
+    /// println!("Random i64: {}", random_i64);
+    /// ```
+    ///
+    /// # Returns
+    /// An `i64` representing a randomly generated 64-bit signed integer.
+    pub fn i64(&mut self) -> i64 {
+        let high = self.rand() as i64;
+        let low = self.rand() as i64;
+        (high << 32) | low
+    }
+
+    /// Generates a random 64-bit unsigned integer.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let random_u64 = rng.u64();
+    /// println!("Random u64: {}", random_u64);
+    /// ```
+    ///
+    /// # Returns
+    /// A `u64` representing a randomly generated 64-bit unsigned integer.
+    pub fn u64(&mut self) -> u64 {
+        let high = self.rand() as u64;
+        let low = self.rand() as u64;
+        (high << 32) | low
+    }
+
+    /// Generates a random 64-bit floating-point number in the range [0.0, 1.0).
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let random_f64 = rng.f64();
+    /// println!("Random f64: {}", random_f64);
+    /// ```
+    ///
+    /// # Returns
+    /// An `f64` representing a randomly generated 64-bit floating-point number.
+    pub fn f64(&mut self) -> f64 {
+        self.double()
+    }
+
+    /// Generates a random 64-bit floating-point number in the open interval (0.0, 1.0).
+    ///
+    /// Two `rand()` outputs are combined into a 52-bit integer `k`, and the result is `(k + 0.5) / 2^52`.
+    /// Every such value is exactly representable, so the result is never 0.0 or 1.0, which makes it safe
+    /// to pass to `ln` or an inverse CDF without special cases.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let u = rng.f64_open();
+    /// assert!(u > 0.0 && u < 1.0);
+    /// ```
+    ///
+    /// # Returns
+    /// An `f64` strictly between 0.0 and 1.0 with 52 bits of randomness.
+    pub fn f64_open(&mut self) -> f64 {
+        let high = u64::from(self.rand() >> 6);
+        let low = u64::from(self.rand() >> 6);
+        let k = (high << 26) | low;
+        (k as f64 + 0.5) / 4_503_599_627_370_496.0
+    }
+
+    /// Generates an antithetic pair of uniforms `(u, 1.0 - u)` from a single [`Random::f64_open`] draw.
+    ///
+    /// Antithetic variates reduce the variance of Monte Carlo estimates of `E[f(U)]` when `f` is monotone:
+    /// averaging `f(u)` and `f(1.0 - u)` cancels much of the noise that two independent draws would add.
+    /// To sample another distribution antithetically, pass both values through its inverse CDF, for
+    /// example `-u.ln() / rate` and `-(1.0 - u).ln() / rate` for an exponential. Both values lie strictly
+    /// between 0.0 and 1.0 and sum to exactly 1.0, so neither needs special handling in `ln`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let estimate = (0..1000)
+    ///     .map(|_| {
+    ///         let (u, v) = rng.antithetic_uniform_pair();
+    ///         (u.exp() + v.exp()) / 2.0
+    ///     })
+    ///     .sum::<f64>()
+    ///     / 1000.0;
+    /// assert!((estimate - (1f64.exp() - 1.0)).abs() < 0.01);
+    /// ```
+    ///
+    /// # Returns
+    /// A tuple `(u, 1.0 - u)` where `u` is uniform on the open interval (0.0, 1.0).
+    pub fn antithetic_uniform_pair(&mut self) -> (f64, f64) {
+        let u = self.f64_open();
+        (u, 1.0 - u)
+    }
+
+    /// Generates a "full jitter" exponential backoff delay.
+    ///
+    /// The delay is drawn uniformly from `[0, min(cap, base * 2^attempt)]`, which spreads out retries from many
+    /// clients while still backing off exponentially. If `base * 2^attempt` overflows, the upper bound saturates
+    /// to `cap`.
+    ///
+    /// # Arguments
+    /// * `base` - The base delay for the first attempt.
+    /// * `attempt` - The zero-based retry attempt number.
+    /// * `cap` - The maximum delay that may be returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let cap = Duration::from_secs(30);
+    /// let delay = rng.jittered_backoff(Duration::from_millis(100), 3, cap);
+    /// assert!(delay <= Duration::from_millis(800));
+    /// ```
+    ///
+    /// # Returns
+    /// A `Duration` between zero and the capped exponential bound, inclusive.
+    pub fn jittered_backoff(
+        &mut self,
+        base: Duration,
+        attempt: u32,
+        cap: Duration,
+    ) -> Duration {
+        let bound = 1u32
+            .checked_shl(attempt)
+            .and_then(|factor| base.checked_mul(factor))
+            .map_or(cap, |delay| delay.min(cap));
+        let bound_nanos =
+            u64::try_from(bound.as_nanos()).unwrap_or(u64::MAX);
+        let span = u128::from(bound_nanos) + 1;
+        let nanos = (u128::from(self.u64()) * span) >> 64;
+        Duration::from_nanos(nanos as u64)
+    }
+
+    /// Generates a random `Duration` uniformly distributed between `min` and `max`, inclusive.
+    ///
+    /// The duration is chosen with nanosecond resolution from a single `u64()` draw. Spans wider than
+    /// `u64::MAX` nanoseconds (about 584 years) are truncated to that width above `min`.
+    ///
+    /// # Arguments
+    /// * `min` - The shortest duration that can be returned.
+    /// * `max` - The longest duration that can be returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let min = Duration::from_millis(10);
+    /// let max = Duration::from_millis(50);
+    /// let delay = rng.duration(min, max);
+    /// assert!(delay >= min && delay <= max);
+    /// ```
+    ///
+    /// # Returns
+    /// A `Duration` between `min` and `max`, inclusive.
+    ///
+    /// # Panics
+    /// Panics if `min` is greater than `max`.
+    pub fn duration(
+        &mut self,
+        min: Duration,
+        max: Duration,
+    ) -> Duration {
+        assert!(min <= max, "min must not exceed max for duration");
+        let span = ((max - min).as_nanos() + 1).min(1 << 64);
+        let nanos = (u128::from(self.u64()) * span) >> 64;
+        min + Duration::from_nanos(nanos as u64)
+    }
+
+    /// Generates a random version 4 UUID.
+    ///
+    /// The 16 bytes are drawn from four `rand()` calls, and the version and variant bits are then set as
+    /// required by RFC 4122. Unlike `Uuid::new_v4`, the result is reproducible under a fixed seed.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let id = rng.uuid();
+    /// assert_eq!(id.get_version_num(), 4);
+    /// ```
+    ///
+    /// # Returns
+    /// A random `uuid::Uuid` of version 4.
+    pub fn uuid(&mut self) -> uuid::Uuid {
+        let mut bytes = [0u8; 16];
+        for chunk in bytes.chunks_mut(4) {
+            chunk.copy_from_slice(&self.rand().to_be_bytes());
+        }
+        uuid::Builder::from_random_bytes(bytes).into_uuid()
+    }
+
+    /// Generates a random IPv4 address from a single `rand()` draw.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let address = rng.random_ipv4();
+    /// println!("Random IPv4 address: {}", address);
+    /// ```
+    ///
+    /// # Returns
+    /// An `Ipv4Addr` drawn uniformly from the whole address space.
+    pub fn random_ipv4(&mut self) -> Ipv4Addr {
+        Ipv4Addr::from(self.rand())
+    }
+
+    /// Generates a random IPv4 address within the CIDR block `base/prefix_len`.
+    ///
+    /// The first `prefix_len` bits are taken from `base` and the remaining host bits are random.
+    ///
+    /// # Arguments
+    /// * `base` - Any address inside the block. Its host bits are ignored.
+    /// * `prefix_len` - The length of the network prefix, between 0 and 32.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::net::Ipv4Addr;
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let address = rng.random_ipv4_in(Ipv4Addr::new(10, 1, 0, 0), 16);
+    /// assert_eq!(address.octets()[..2], [10, 1]);
+    /// ```
+    ///
+    /// # Returns
+    /// An `Ipv4Addr` inside the block.
+    ///
+    /// # Panics
+    /// Panics if `prefix_len` is greater than 32.
+    pub fn random_ipv4_in(
+        &mut self,
+        base: Ipv4Addr,
+        prefix_len: u8,
+    ) -> Ipv4Addr {
+        assert!(
+            prefix_len <= 32,
+            "prefix_len must not exceed 32 for random_ipv4_in"
+        );
+        let mask = u32::MAX
+            .checked_shl(32 - u32::from(prefix_len))
+            .unwrap_or(0);
+        Ipv4Addr::from((u32::from(base) & mask) | (self.rand() & !mask))
+    }
+
+    /// Generates a random IPv6 address from four `rand()` draws.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let address = rng.random_ipv6();
+    /// println!("Random IPv6 address: {}", address);
+    /// ```
+    ///
+    /// # Returns
+    /// An `Ipv6Addr` drawn uniformly from the whole address space.
+    pub fn random_ipv6(&mut self) -> Ipv6Addr {
+        Ipv6Addr::from(self.random_u128())
+    }
+
+    /// Generates a random IPv6 address within the CIDR block `base/prefix_len`.
+    ///
+    /// The first `prefix_len` bits are taken from `base` and the remaining host bits are random.
+    ///
+    /// # Arguments
+    /// * `base` - Any address inside the block. Its host bits are ignored.
+    /// * `prefix_len` - The length of the network prefix, between 0 and 128.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::net::Ipv6Addr;
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let base = "2001:db8::".parse::<Ipv6Addr>().unwrap();
+    /// let address = rng.random_ipv6_in(base, 32);
+    /// assert_eq!(address.segments()[..2], [0x2001, 0xdb8]);
+    /// ```
+    ///
+    /// # Returns
+    /// An `Ipv6Addr` inside the block.
+    ///
+    /// # Panics
+    /// Panics if `prefix_len` is greater than 128.
+    pub fn random_ipv6_in(
+        &mut self,
+        base: Ipv6Addr,
+        prefix_len: u8,
+    ) -> Ipv6Addr {
+        assert!(
+            prefix_len <= 128,
+            "prefix_len must not exceed 128 for random_ipv6_in"
+        );
+        let mask = u128::MAX
+            .checked_shl(128 - u32::from(prefix_len))
+            .unwrap_or(0);
+        let host = self.random_u128() & !mask;
+        Ipv6Addr::from((u128::from(base) & mask) | host)
+    }
+
+    /// Combines four `rand()` outputs into a `u128`, most significant word first.
+    fn random_u128(&mut self) -> u128 {
+        (0..4).fold(0u128, |value, _| {
+            (value << 32) | u128::from(self.rand())
+        })
+    }
+
+    /// Generates a random string of the specified length.
+    ///
+    /// # Arguments
+    /// * `length` - The desired length of the random string.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let random_string = rng.string(10);
+    /// println!("Random string: {}", random_string);
+    /// ```
+    ///
+    /// # Returns
+    /// A `String` representing a randomly generated string of the specified length.
+    pub fn string(&mut self, length: usize) -> String {
+        let mut res = String::with_capacity(length);
+        self.string_into(&mut res, length);
+        res
+    }
+
+    /// Clears `buf` and refills it with a random string of `len` characters, reusing its existing
+    /// capacity.
+    ///
+    /// Produces the same characters as [`Random::string`], but lets a caller generating many strings in a
+    /// loop keep one allocation instead of creating a new `String` on every call.
+    ///
+    /// # Arguments
+    /// * `buf` - The buffer to overwrite. Any previous contents are discarded.
+    /// * `len` - The number of random characters to write into `buf`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let mut buf = String::new();
+    /// for len in [16, 4, 8] {
+    ///     rng.string_into(&mut buf, len);
+    ///     assert_eq!(buf.chars().count(), len);
+    /// }
+    /// ```
+    pub fn string_into(&mut self, buf: &mut String, len: usize) {
+        buf.clear();
+        buf.reserve(len);
+        for _ in 0..len {
+            buf.push(self.char());
+        }
+    }
+
+    /// Generates a random string of the specified length from a chosen alphabet.
+    ///
+    /// Every character is drawn from the internal generator with rejection sampling, so each character of
+    /// the alphabet is equally likely and the output is reproducible under a fixed seed.
+    ///
+    /// # Arguments
+    /// * `length` - The desired length of the random string, in characters.
+    /// * `charset` - The alphabet to draw characters from.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::{Random, StringCharset};
+    /// let mut rng = Random::new();
+    /// let token = rng.string_with(32, StringCharset::Hex);
+    /// assert!(token.chars().all(|c| c.is_ascii_hexdigit()));
+    /// ```
+    ///
+    /// # Returns
+    /// A `String` of `length` characters, each taken from `charset`.
+    ///
+    /// # Panics
+    /// Panics if `charset` is `StringCharset::Custom` with no characters.
+    pub fn string_with(
+        &mut self,
+        length: usize,
+        charset: StringCharset,
+    ) -> String {
+        let alphabet = charset.alphabet();
+        assert!(
+            !alphabet.is_empty(),
+            "charset must not be empty for string_with"
+        );
+        let span = alphabet.len() as u64;
+        // Only accept draws below the largest multiple of `span` to avoid modulo bias.
+        let limit = (1u64 << 32) / span * span;
+        (0..length)
+            .map(|_| loop {
+                let value = u64::from(self.rand());
+                if value < limit {
+                    break alphabet[(value % span) as usize];
+                }
+            })
+            .collect()
+    }
+
+    /// Generates a random password that contains at least one character from each required class.
+    ///
+    /// One character is drawn from every required class first, the remaining positions are filled from the
+    /// union of the required classes, and the result is shuffled so the guaranteed characters do not sit at
+    /// predictable positions. Every character and every shuffle swap is drawn with rejection sampling, so no
+    /// character is favoured by modulo bias.
+    ///
+    /// # Security
+    /// Mersenne Twister output is predictable: 624 consecutive outputs reveal the whole state, and this crate
+    /// itself ships [`Random::untemper`] for recovering it. Passwords generated here are only suitable for
+    /// test fixtures and sample data. Never use this method for real credentials; draw them from the
+    /// operating system's CSPRNG instead, for example through `rand::rngs::OsRng`.
+    ///
+    /// # Arguments
+    /// * `length` - The desired length of the password.
+    /// * `require_lower` - Whether to include lowercase letters (`a-z`).
+    /// * `require_upper` - Whether to include uppercase letters (`A-Z`).
+    /// * `require_digit` - Whether to include digits (`0-9`).
+    /// * `require_symbol` - Whether to include symbols (`!#$%&*+-=?@^_~`).
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let password = rng.generate_password(16, true, true, true, false);
+    /// assert_eq!(password.len(), 16);
+    /// assert!(password.chars().any(|c| c.is_ascii_digit()));
+    /// ```
+    ///
+    /// # Returns
+    /// A `String` of `length` characters drawn from the enabled classes.
+    ///
+    /// # Panics
+    /// Panics if no class is enabled, or if `length` is smaller than the number of required classes.
+    pub fn generate_password(
+        &mut self,
+        length: usize,
+        require_lower: bool,
+        require_upper: bool,
+        require_digit: bool,
+        require_symbol: bool,
+    ) -> String {
+        const LOWER: &[u8] = b"abcdefghijklmnopqrstuvwxyz";
+        const UPPER: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        const DIGITS: &[u8] = b"0123456789";
+        const SYMBOLS: &[u8] = b"!#$%&*+-=?@^_~";
+
+        let classes: Vec<&[u8]> = [
+            (require_lower, LOWER),
+            (require_upper, UPPER),
+            (require_digit, DIGITS),
+            (require_symbol, SYMBOLS),
+        ]
+        .iter()
+        .filter(|(required, _)| *required)
+        .map(|&(_, class)| class)
+        .collect();
+        assert!(
+            !classes.is_empty(),
+            "at least one character class must be enabled for generate_password"
+        );
+        assert!(
+            length >= classes.len(),
+            "length must be at least the number of required classes for generate_password"
+        );
+
+        let alphabet: Vec<u8> = classes.concat();
+        let mut password: Vec<u8> = Vec::with_capacity(length);
+        for class in &classes {
+            let index = self.index_below(class.len());
+            password.push(class[index]);
+        }
+        while password.len() < length {
+            let index = self.index_below(alphabet.len());
+            password.push(alphabet[index]);
+        }
+        for i in (1..password.len()).rev() {
+            let j = self.index_below(i + 1);
+            password.swap(i, j);
+        }
+        password.into_iter().map(char::from).collect()
+    }
+
+    /// Generates a random number from a standard normal distribution (mean = 0, stddev = 1).
+    ///
+    /// # Arguments
+    /// * `mu` - The mean of the normal distribution.
+    /// * `sigma` - The standard deviation of the normal distribution.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let normal = rng.normal(0.0, 1.0);
+    /// println!("Random number from standard normal distribution: {}", normal);
+    /// ```
+    ///
+    /// # Returns
+    /// An `f64` representing a random number from a standard normal distribution.
+    pub fn normal(&mut self, mu: f64, sigma: f64) -> f64 {
+        let u1 = self.f64();
+        let u2 = self.f64();
+        let z0 = math::sqrt(-2.0 * math::ln(u1))
+            * math::cos(2.0 * std::f64::consts::PI * u2);
+        mu + sigma * z0
+    }
+
+    /// Generates a normal random number clamped to the interval `[low, high]`.
+    ///
+    /// A single value is drawn with [`Random::normal`] and clamped to the bounds. This is statistically
+    /// different from a truncated normal, which redraws out-of-range values: clamping moves all of the
+    /// probability mass outside the interval onto `low` and `high`, so the boundaries occur with positive
+    /// probability.
+    ///
+    /// # Arguments
+    /// * `mu` - The mean of the normal distribution.
+    /// * `sigma` - The standard deviation of the normal distribution.
+    /// * `low` - The lower bound of the result.
+    /// * `high` - The upper bound of the result.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let value = rng.clamped_normal(0.0, 1.0, -1.0, 1.0);
+    /// assert!((-1.0..=1.0).contains(&value));
+    /// ```
+    ///
+    /// # Returns
+    /// An `f64` between `low` and `high`, inclusive.
+    ///
+    /// # Panics
+    /// Panics if `low` is greater than `high` or either bound is NaN.
+    pub fn clamped_normal(
+        &mut self,
+        mu: f64,
+        sigma: f64,
+        low: f64,
+        high: f64,
+    ) -> f64 {
+        assert!(
+            low <= high,
+            "low must not exceed high for clamped_normal"
+        );
+        self.normal(mu, sigma).clamp(low, high)
+    }
+
+    /// Generates a pair of standard normal random numbers with a given correlation.
+    ///
+    /// Two independent standard normals `x` and `y` are drawn with [`Random::normal`] and combined as
+    /// `z1 = x` and `z2 = rho * x + sqrt(1 - rho^2) * y`. Both values are standard normal and their Pearson
+    /// correlation is `rho`.
+    ///
+    /// # Arguments
+    /// * `rho` - The target correlation, between `-1.0` and `1.0` inclusive.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let (stock, index) = rng.correlated_normals(0.8);
+    /// println!("Returns: {} {}", stock, index);
+    /// ```
+    ///
+    /// # Returns
+    /// A tuple `(z1, z2)` of standard normal `f64` values with correlation `rho`.
+    ///
+    /// # Panics
+    /// Panics if `rho` is not between `-1.0` and `1.0`, or is `NaN`.
+    pub fn correlated_normals(&mut self, rho: f64) -> (f64, f64) {
+        assert!(
+            (-1.0..=1.0).contains(&rho),
+            "rho must be between -1.0 and 1.0 for correlated_normals"
+        );
+        let x = self.normal(0.0, 1.0);
+        let y = self.normal(0.0, 1.0);
+        (x, rho * x + math::sqrt(1.0 - rho * rho) * y)
+    }
+
+    /// Generates a random unit vector uniformly distributed on the surface of a `dim`-dimensional sphere.
+    ///
+    /// The vector is built by drawing `dim` independent standard normals and normalizing the result, which
+    /// gives a direction that is uniform over the sphere for any dimension.
+    ///
+    /// # Arguments
+    /// * `dim` - The number of dimensions of the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let vector = rng.random_unit_vector_n(3);
+    /// let length = vector.iter().map(|x| x * x).sum::<f64>().sqrt();
+    /// assert!((length - 1.0).abs() < 1e-9);
+    /// ```
+    ///
+    /// # Returns
+    /// A `Vec<f64>` of length `dim` with a Euclidean norm of 1.0.
+    ///
+    /// # Panics
+    /// Panics if `dim` is zero.
+    pub fn random_unit_vector_n(&mut self, dim: usize) -> Vec<f64> {
+        assert!(
+            dim >= 1,
+            "dim must be at least 1 for random_unit_vector_n"
+        );
+        loop {
+            let vector: Vec<f64> =
+                (0..dim).map(|_| self.normal(0.0, 1.0)).collect();
+            let norm =
+                math::sqrt(vector.iter().map(|x| x * x).sum::<f64>());
+            // A zero or non-finite norm cannot be normalized, so draw again.
+            if norm > 0.0 && norm.is_finite() {
+                return vector.into_iter().map(|x| x / norm).collect();
+            }
+        }
+    }
+
+    /// Generates a random point uniformly distributed on the probability simplex with `n` components.
+    ///
+    /// `n - 1` uniforms are drawn and sorted, and the gaps between `0.0`, the sorted values and `1.0` are
+    /// returned. Every composition is equally likely, which matches a Dirichlet distribution with all
+    /// concentrations equal to 1.
+    ///
+    /// # Arguments
+    /// * `n` - The number of components.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let weights = rng.random_simplex(4);
+    /// assert!((weights.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    /// ```
+    ///
+    /// # Returns
+    /// A `Vec<f64>` of `n` non-negative values that sum to 1.0.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    pub fn random_simplex(&mut self, n: usize) -> Vec<f64> {
+        assert!(n >= 1, "n must be at least 1 for random_simplex");
+        let mut cuts: Vec<f64> = (1..n).map(|_| self.f64()).collect();
+        // Draws are never NaN, so every pair is comparable.
+        cuts.sort_unstable_by(|a, b| {
+            a.partial_cmp(b).unwrap_or(Ordering::Equal)
+        });
+        cuts.push(1.0);
+        let mut previous = 0.0;
+        cuts.into_iter()
+            .map(|cut| {
+                let gap = cut - previous;
+                previous = cut;
+                gap
+            })
+            .collect()
+    }
+
+    /// Generates `n` uniform random numbers in ascending order, in `O(n)` time.
+    ///
+    /// Rather than sorting independent uniforms, `n + 1` standard exponential spacings are drawn and their
+    /// running sums are divided by the total. The result has exactly the distribution of `n` sorted
+    /// independent uniforms, so value `i` (counting from 1) has mean `i / (n + 1)`.
+    ///
+    /// # Arguments
+    /// * `n` - The number of values to generate.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let values = rng.sorted_uniforms(5);
+    /// assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+    /// ```
+    ///
+    /// # Returns
+    /// A `Vec<f64>` of `n` non-decreasing values between 0.0 and 1.0.
+    pub fn sorted_uniforms(&mut self, n: usize) -> Vec<f64> {
+        let mut running = 0.0;
+        let sums: Vec<f64> = (0..=n)
+            .map(|_| {
+                running += -math::ln(self.f64_open());
+                running
+            })
+            .collect();
+        let total = sums[n];
+        sums[..n].iter().map(|&sum| sum / total).collect()
+    }
+
+    /// Generates `n` stratified uniforms, one in each of `n` equal subintervals of [0.0, 1.0).
+    ///
+    /// Value `i` is `(i + u) / n` for an independent [`Random::f64`] draw `u`, so it is uniform on
+    /// `[i / n, (i + 1) / n)`. Every stratum receives exactly one sample, which spreads the values more
+    /// evenly than independent uniforms and lowers the variance of Monte Carlo estimates. The values are
+    /// returned in stratum order; shuffle them if the pairing with other inputs must be random.
+    ///
+    /// # Arguments
+    /// * `n` - The number of strata and values.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let values = rng.stratified_uniforms(4);
+    /// assert!(values[2] >= 0.5 && values[2] < 0.75);
+    /// ```
+    ///
+    /// # Returns
+    /// A `Vec<f64>` of `n` values, the `i`-th of which lies in `[i / n, (i + 1) / n)`.
+    pub fn stratified_uniforms(&mut self, n: usize) -> Vec<f64> {
+        let strata = n as f64;
+        (0..n)
+            .map(|i| {
+                let upper = (i + 1) as f64 / strata;
+                let value = (i as f64 + self.f64()) / strata;
+                // Rounding can carry a draw just below 1.0 onto the upper bound.
+                if value < upper {
+                    value
+                } else {
+                    f64::from_bits(upper.to_bits() - 1)
+                }
+            })
+            .collect()
+    }
+
+    /// Generates the minimum of `n` independent uniforms on [0.0, 1.0) from a single uniform draw.
+    ///
+    /// The minimum has CDF `1 - (1 - x)^n`, so it is sampled directly by inversion as
+    /// `1 - (1 - u)^(1 / n)` for one [`Random::f64`] draw `u`, instead of generating all `n` values. Its
+    /// mean is `1 / (n + 1)`. Scaling the result models weakest-link failure times.
+    ///
+    /// # Arguments
+    /// * `n` - The number of uniforms the minimum is taken over. Must be positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let weakest = rng.min_of_uniforms(100);
+    /// assert!((0.0..1.0).contains(&weakest));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    ///
+    /// # Returns
+    /// An `f64` in the interval [0.0, 1.0) distributed as the smallest of `n` uniforms.
+    pub fn min_of_uniforms(&mut self, n: u32) -> f64 {
+        assert!(n > 0, "n must be positive for min_of_uniforms");
+        1.0 - math::powf(1.0 - self.f64(), 1.0 / f64::from(n))
+    }
+
+    /// Generates the maximum of `n` independent uniforms on [0.0, 1.0) from a single uniform draw.
+    ///
+    /// The maximum has CDF `x^n`, so it is sampled directly by inversion as `u^(1 / n)` for one
+    /// [`Random::f64`] draw `u`, instead of generating all `n` values. Its mean is `n / (n + 1)`.
+    ///
+    /// # Arguments
+    /// * `n` - The number of uniforms the maximum is taken over. Must be positive.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let strongest = rng.max_of_uniforms(100);
+    /// assert!((0.0..1.0).contains(&strongest));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    ///
+    /// # Returns
+    /// An `f64` in the interval [0.0, 1.0) distributed as the largest of `n` uniforms.
+    pub fn max_of_uniforms(&mut self, n: u32) -> f64 {
+        assert!(n > 0, "n must be positive for max_of_uniforms");
+        math::powf(self.f64(), 1.0 / f64::from(n))
+    }
+
+    /// Generates a `rows` by `cols` matrix of uniform values, stored as a flat row-major vector.
+    ///
+    /// Entry `(i, j)` is at index `i * cols + j`, and every entry is drawn with [`Random::f64`].
+    ///
+    /// # Arguments
+    /// * `rows` - The number of rows in the matrix.
+    /// * `cols` - The number of columns in the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let matrix = rng.random_matrix(3, 4);
+    /// assert_eq!(matrix.len(), 12);
+    /// assert!(matrix.iter().all(|&x| (0.0..1.0).contains(&x)));
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `rows * cols` overflows `usize`.
+    ///
+    /// # Returns
+    /// A `Vec<f64>` of `rows * cols` values in the interval [0.0, 1.0).
+    pub fn random_matrix(
+        &mut self,
+        rows: usize,
+        cols: usize,
+    ) -> Vec<f64> {
+        let len = rows
+            .checked_mul(cols)
+            .expect("rows * cols must fit in usize for random_matrix");
+        (0..len).map(|_| self.f64()).collect()
+    }
+
+    /// Generates a `rows` by `cols` matrix of normal values, stored as a flat row-major vector.
+    ///
+    /// Entry `(i, j)` is at index `i * cols + j`, and every entry is drawn with [`Random::normal`], which
+    /// suits Gaussian weight initialization.
+    ///
+    /// # Arguments
+    /// * `rows` - The number of rows in the matrix.
+    /// * `cols` - The number of columns in the matrix.
+    /// * `mu` - The mean of every entry.
+    /// * `sigma` - The standard deviation of every entry.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let weights = rng.normal_matrix(16, 8, 0.0, 0.1);
+    /// assert_eq!(weights.len(), 128);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `rows * cols` overflows `usize`.
+    ///
+    /// # Returns
+    /// A `Vec<f64>` of `rows * cols` normally distributed values.
+    pub fn normal_matrix(
+        &mut self,
+        rows: usize,
+        cols: usize,
+        mu: f64,
+        sigma: f64,
+    ) -> Vec<f64> {
+        let len = rows
+            .checked_mul(cols)
+            .expect("rows * cols must fit in usize for normal_matrix");
+        (0..len).map(|_| self.normal(mu, sigma)).collect()
+    }
+
+    /// Generates a random number from an exponential distribution with the specified rate parameter.
+    ///
+    /// # Arguments
+    /// * `rate` - The rate parameter (lambda) of the exponential distribution.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let exponential = rng.exponential(1.5);
+    /// println!("Random number from exponential distribution with rate 1.5: {}", exponential);
+    /// ```
+    ///
+    /// # Returns
+    /// An `f64` representing a random number from an exponential distribution.
+    ///
+    /// # Notes
+    /// The uniform variate is drawn with [`Random::f64_open`], so the logarithm is always finite and the
+    /// result is finite and non-negative for any positive `rate`.
+    pub fn exponential(&mut self, rate: f64) -> f64 {
+        -1.0 / rate * math::ln(self.f64_open())
+    }
+
+    /// Generates a random number from a Poisson distribution with the specified mean parameter.
+    ///
+    /// # Arguments
+    /// * `mean` - The mean parameter (lambda) of the Poisson distribution.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let poisson = rng.poisson(3.0);
+    /// println!("Random number from Poisson distribution with mean 3.0: {}", poisson);
+    /// ```
+    ///
+    /// # Returns
+    /// An `u64` representing a random number from a Poisson distribution.
+    ///
+    /// # Notes
+    /// - Means below 10.0 use Knuth's multiplication method, which consumes `k + 1` uniform draws.
+    /// - Larger means use Hörmann's transformed rejection method (PTRS), which runs in constant expected
+    ///   time and stays correct where `exp(-mean)` would underflow.
+    pub fn poisson(&mut self, mean: f64) -> u64 {
+        if mean >= POISSON_PTRS_THRESHOLD {
+            PoissonPtrs::new(mean).sample(self)
+        } else {
+            self.poisson_knuth(math::exp(-mean))
+        }
+    }
+
+    /// Generates a random number from a Poisson distribution and returns it as an `f64`.
+    ///
+    /// This draws exactly as [`Random::poisson`] does, which is convenient when the count is combined
+    /// with continuous distributions in a `Vec<f64>`. Counts below `2^53` convert exactly.
+    ///
+    /// # Arguments
+    /// * `mean` - The mean parameter (lambda) of the Poisson distribution.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let samples = vec![rng.normal(0.0, 1.0), rng.poisson_f64(3.0)];
+    /// println!("Mixed samples: {:?}", samples);
+    /// ```
+    ///
+    /// # Returns
+    /// An `f64` holding a non-negative whole number drawn from a Poisson distribution.
+    pub fn poisson_f64(&mut self, mean: f64) -> f64 {
+        self.poisson(mean) as f64
+    }
+
+    /// Counts the successes in `n` independent trials that each succeed with probability `p`.
+    ///
+    /// The count follows a binomial distribution with mean `n * p`.
+    ///
+    /// # Arguments
+    /// * `n` - The number of trials.
+    /// * `p` - The success probability of each trial, between 0.0 and 1.0.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let heads = rng.bernoulli_trials(10, 0.5);
+    /// assert!(heads <= 10);
+    /// ```
+    ///
+    /// # Notes
+    /// - Up to 64 trials with `p` exactly `0.5` are bit-buffered: each trial is one bit of the stream read by
+    ///   [`Random::bits_be`], so bits left over from an earlier call are used first and unused bits of the
+    ///   last word stay buffered for the next one. A `0` bit is a success, the same convention as
+    ///   `bool(0.5)`, so on a fresh generator a single trial agrees with `bool(0.5)`.
+    /// - Up to 64 trials with any other `p` are run one by one with [`Random::bool`], one `rand()` draw per
+    ///   trial, and do not touch the bit buffer.
+    /// - Larger counts sum geometric gaps between successes of the rarer outcome, which stays exact and
+    ///   consumes about `n * min(p, 1 - p) + 1` uniform draws.
+    ///
+    /// # Panics
+    /// Panics if `p` is not between 0.0 and 1.0.
+    pub fn bernoulli_trials(&mut self, n: u32, p: f64) -> u32 {
+        assert!(
+            (0.0..=1.0).contains(&p),
+            "p must be between 0.0 and 1.0 for bernoulli_trials"
+        );
+        if p == 0.0 || n == 0 {
+            return 0;
+        }
+        if p == 1.0 {
+            return n;
+        }
+        if n > BERNOULLI_DIRECT_MAX_TRIALS {
+            return if p > 0.5 {
+                n - self.binomial_waiting_time(n, 1.0 - p)
+            } else {
+                self.binomial_waiting_time(n, p)
+            };
+        }
+        if p == 0.5 {
+            let mut remaining = n;
+            let mut successes = 0;
+            while remaining > 0 {
+                let bits = remaining.min(32);
+                successes +=
+                    bits - self.take_bits(bits as u8).count_ones();
+                remaining -= bits;
+            }
+            return successes;
+        }
+        (0..n).map(|_| u32::from(self.bool(p))).sum()
+    }
+
+    /// Counts successes in `n` trials with success probability `p <= 0.5` by summing geometric gaps
+    /// between consecutive successes until they pass `n`.
+    fn binomial_waiting_time(&mut self, n: u32, p: f64) -> u32 {
+        let log_q = math::ln_1p(-p);
+        let mut position = 0.0;
+        let mut successes = 0;
+        loop {
+            position +=
+                (math::ln(self.f64_open()) / log_q).floor() + 1.0;
+            if position > f64::from(n) {
+                return successes;
+            }
+            successes += 1;
+        }
+    }
+
+    /// Generates the event times of a homogeneous Poisson process over the window `[0, duration)`.
+    ///
+    /// Inter-arrival times are drawn with [`Random::exponential`] and accumulated until the running time
+    /// reaches `duration`. The number of events is Poisson distributed with mean `rate * duration`.
+    ///
+    /// # Arguments
+    /// * `rate` - The expected number of events per unit of time.
+    /// * `duration` - The length of the time window.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let arrivals = rng.poisson_process(2.0, 10.0);
+    /// assert!(arrivals.iter().all(|&t| (0.0..10.0).contains(&t)));
+    /// ```
+    ///
+    /// # Returns
+    /// A `Vec<f64>` of event times in increasing order, each in `[0, duration)`.
+    ///
+    /// # Panics
+    /// Panics if `rate` or `duration` is not positive and finite.
+    pub fn poisson_process(
+        &mut self,
+        rate: f64,
+        duration: f64,
+    ) -> Vec<f64> {
+        assert!(
+            rate > 0.0 && rate.is_finite(),
+            "rate must be positive and finite for poisson_process"
+        );
+        assert!(
+            duration > 0.0 && duration.is_finite(),
+            "duration must be positive and finite for poisson_process"
+        );
+        let mut events = Vec::new();
+        let mut time = self.exponential(rate);
+        while time < duration {
+            events.push(time);
+            time += self.exponential(rate);
+        }
+        events
+    }
+
+    /// Generates `n` random numbers from a Poisson distribution with the specified mean parameter.
+    ///
+    /// The constants of the underlying algorithm (the `exp(-mean)` threshold for small means, or the
+    /// rejection constants for large means) are computed once and shared across all samples. The output is
+    /// identical to calling [`Random::poisson`] `n` times in a row.
+    ///
+    /// # Arguments
+    /// * `mean` - The mean parameter (lambda) of the Poisson distribution.
+    /// * `n` - The number of samples to generate.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let samples = rng.poisson_batch(3.0, 100);
+    /// assert_eq!(samples.len(), 100);
+    /// ```
+    ///
+    /// # Returns
+    /// A `Vec<u64>` containing `n` samples from a Poisson distribution.
+    pub fn poisson_batch(&mut self, mean: f64, n: usize) -> Vec<u64> {
+        if mean >= POISSON_PTRS_THRESHOLD {
+            let ptrs = PoissonPtrs::new(mean);
+            (0..n).map(|_| ptrs.sample(self)).collect()
+        } else {
+            let l = math::exp(-mean);
+            (0..n).map(|_| self.poisson_knuth(l)).collect()
+        }
+    }
+
+    /// Draws `samples` outcomes from a discrete sampler and counts how often each one occurs.
+    ///
+    /// This is a quick way to check the shape of a discrete distribution, such as `poisson`, against its
+    /// expected probabilities without pulling in a statistics crate.
+    ///
+    /// # Arguments
+    /// * `sampler` - A closure drawing one outcome from the generator it is given.
+    /// * `samples` - The number of outcomes to draw.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let counts = rng.sample_counts(|rng| rng.poisson(3.0), 1_000);
+    /// assert_eq!(counts.values().sum::<usize>(), 1_000);
+    /// ```
+    ///
+    /// # Returns
+    /// A `HashMap` from each observed outcome to the number of times it was drawn.
+    pub fn sample_counts<F: FnMut(&mut Random) -> u64>(
+        &mut self,
+        mut sampler: F,
+        samples: usize,
+    ) -> HashMap<u64, usize> {
+        let mut counts = HashMap::new();
+        for _ in 0..samples {
+            *counts.entry(sampler(self)).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Draws a Poisson variate using Knuth's method, given the precomputed threshold `l = exp(-mean)`.
+    pub(crate) fn poisson_knuth(&mut self, l: f64) -> u64 {
+        let mut k = 0;
+        let mut p = 1.0;
+        loop {
+            k += 1;
+            p *= self.f64();
+            if p < l {
+                break;
+            }
+        }
+        k - 1
+    }
+
+    /// Generates a random subslice of the specified length from the given slice.
+    ///
+    /// # Arguments
+    /// * `slice` - The slice from which to generate a random subslice.
+    /// * `length` - The desired length of the random subslice.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let slice = &[1, 2, 3, 4, 5];
+    /// let random_subslice = rng.rand_slice(slice, 3);
+    /// println!("Random subslice: {:?}", random_subslice);
+    /// ```
+    ///
+    /// # Returns
+    /// A `Result<&[T], &str>` containing a randomly generated subslice of the specified length.
+    pub fn rand_slice<'a, T>(
+        &mut self,
+        slice: &'a [T],
+        length: usize,
+    ) -> Result<&'a [T], &'static str> {
+        if slice.is_empty() {
+            return Err("Input slice is empty");
+        }
+        if length == 0 {
+            return Err("Requested length must be greater than zero");
+        }
+        if length > slice.len() {
+            return Err("Requested length exceeds slice length");
+        }
+
+        let available_start_positions = slice.len() - length + 1;
+        let start = self
+            .random_range(0, available_start_positions as u32)
+            as usize;
+        Ok(&slice[start..start + length])
+    }
+
+    /// Randomly samples elements from the given slice without replacement.
+    ///
+    /// # Arguments
+    /// * `slice` - The slice from which to sample elements.
+    /// * `amount` - The number of elements to sample.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let slice = &[1, 2, 3, 4, 5];
+    /// let samples = rng.sample(slice, 3);
+    /// println!("Random samples: {:?}", samples);
+    /// ```
+    ///
+    /// # Returns
+    /// A vector containing the randomly sampled elements.
+    pub fn sample<'a, T>(
+        &'a mut self,
+        slice: &'a [T],
+        amount: usize,
+    ) -> Vec<&'a T> {
+        let mut result = Vec::with_capacity(amount);
+        let mut indices: Vec<usize> = (0..slice.len()).collect();
+        for _ in 0..amount {
+            let index =
+                self.random_range(0, indices.len() as u32) as usize;
+            result.push(&slice[indices[index]]);
+            indices.remove(index);
+        }
+        result
+    }
+
+    /// Removes `k` randomly chosen elements from `pool` and returns them.
+    ///
+    /// Each element is taken with `swap_remove`, so draining costs `O(k)` regardless of the pool size,
+    /// but the order of the elements left in `pool` is not preserved. Unlike [`Random::sample`], the
+    /// chosen elements are moved out rather than borrowed, which suits simulations that repeatedly draw
+    /// from a pool and refill it.
+    ///
+    /// # Arguments
+    /// * `pool` - The vector to draw elements from. It keeps the elements that were not chosen.
+    /// * `k` - The number of elements to draw. Values above `pool.len()` drain the whole pool.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let mut deck: Vec<u32> = (1..=52).collect();
+    /// let hand = rng.drain_sample(&mut deck, 5);
+    /// assert_eq!(hand.len(), 5);
+    /// assert_eq!(deck.len(), 47);
+    /// ```
+    ///
+    /// # Returns
+    /// A vector of the `min(k, pool.len())` removed elements, in the order they were drawn.
+    pub fn drain_sample<T>(
+        &mut self,
+        pool: &mut Vec<T>,
+        k: usize,
+    ) -> Vec<T> {
+        let amount = k.min(pool.len());
+        let mut result = Vec::with_capacity(amount);
+        for _ in 0..amount {
+            let index =
+                self.random_range(0, pool.len() as u32) as usize;
+            result.push(pool.swap_remove(index));
+        }
+        result
+    }
+
+    /// Randomly samples elements from the given slice with replacement.
+    ///
+    /// # Arguments
+    /// * `slice` - The slice from which to sample elements.
+    /// * `amount` - The number of elements to sample.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let slice = &[1, 2, 3, 4, 5];
+    /// let samples = rng.sample_with_replacement(slice, 3);
+    /// println!("Random samples with replacement: {:?}", samples);
+    /// ```
+    ///
+    /// # Returns
+    /// A vector containing the randomly sampled elements with replacement.
+    pub fn sample_with_replacement<'a, T>(
+        &'a mut self,
+        slice: &'a [T],
+        amount: usize,
+    ) -> Vec<&'a T> {
+        let mut result = Vec::with_capacity(amount);
+        for _ in 0..amount {
+            let index =
+                self.random_range(0, slice.len() as u32) as usize;
+            result.push(&slice[index]);
+        }
+        result
+    }
+
+    /// Selects a random subset of the given slice, keeping each element independently with probability `p`.
+    ///
+    /// Each element consumes exactly one draw through [`Random::bool`], and the selected elements keep
+    /// their original order.
+    ///
+    /// # Arguments
+    /// * `slice` - The slice from which to select elements.
+    /// * `p` - The probability of keeping each element, between 0.0 and 1.0.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let slice = &[1, 2, 3, 4, 5];
+    /// let subset = rng.subset(slice, 0.5);
+    /// println!("Random subset: {:?}", subset);
+    /// ```
+    ///
+    /// # Returns
+    /// A vector containing references to the selected elements.
+    ///
+    /// # Panics
+    /// Panics if `p` is not between 0.0 and 1.0.
+    pub fn subset<'a, T>(
+        &mut self,
+        slice: &'a [T],
+        p: f64,
+    ) -> Vec<&'a T> {
+        assert!(
+            (0.0..=1.0).contains(&p),
+            "p must be between 0.0 and 1.0 for subset"
+        );
+        slice.iter().filter(|_| self.bool(p)).collect()
+    }
+
+    /// Selects up to `k` items from a stream of `(item, weight)` pairs using weighted reservoir sampling.
+    ///
+    /// This is the single-pass A-ExpJ algorithm of Efraimidis and Spirakis: each item is selected with
+    /// probability proportional to its weight, only `O(k)` memory is used, and exponential jumps skip over
+    /// items that cannot enter the reservoir without drawing a number for each of them.
+    ///
+    /// # Arguments
+    /// * `iter` - The `(item, weight)` pairs to sample from.
+    /// * `k` - The maximum number of items to select.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let items = vec![("rare", 1.0), ("common", 10.0), ("usual", 5.0)];
+    /// let picked = rng.weighted_reservoir(items, 2);
+    /// assert_eq!(picked.len(), 2);
+    /// ```
+    ///
+    /// # Returns
+    /// A vector of `min(k, count)` selected items, in no particular order.
+    ///
+    /// # Panics
+    /// Panics if any weight is not positive and finite.
+    pub fn weighted_reservoir<I, T>(
+        &mut self,
+        iter: I,
+        k: usize,
+    ) -> Vec<T>
+    where
+        I: IntoIterator<Item = (T, f64)>,
+    {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut reservoir: BinaryHeap<ReservoirEntry<T>> =
+            BinaryHeap::with_capacity(k);
+        // Keys are stored as ln(u) / weight, which orders items like u^(1 / weight) without underflow.
+        let mut jump = 0.0;
+        for (item, weight) in iter {
+            assert!(
+                weight > 0.0 && weight.is_finite(),
+                "weights must be positive and finite for weighted_reservoir"
+            );
+            if reservoir.len() < k {
+                let key = math::ln(self.f64_open()) / weight;
+                reservoir.push(ReservoirEntry { key, item });
+                if reservoir.len() == k {
+                    jump = self.reservoir_jump(&reservoir);
+                }
+                continue;
+            }
+            jump -= weight;
+            if jump > 0.0 {
+                continue;
+            }
+            let threshold =
+                reservoir.peek().map_or(0.0, |entry| entry.key);
+            let floor = math::exp(weight * threshold);
+            let u = floor + (1.0 - floor) * self.f64_open();
+            let key = math::ln(u) / weight;
+            let _ = reservoir.pop();
+            reservoir.push(ReservoirEntry { key, item });
+            jump = self.reservoir_jump(&reservoir);
+        }
+        reservoir.into_iter().map(|entry| entry.item).collect()
+    }
+
+    /// Draws the total weight to skip before the next item enters a full weighted reservoir.
+    fn reservoir_jump<T>(
+        &mut self,
+        reservoir: &BinaryHeap<ReservoirEntry<T>>,
+    ) -> f64 {
+        let threshold = reservoir.peek().map_or(0.0, |entry| entry.key);
+        math::ln(self.f64_open()) / threshold
+    }
+
+    /// Fills the given mutable buffer of integers with random values.
+    ///
+    /// Each element is filled from the high bits of `rand()` (or from `u64()` for 64-bit elements),
+    /// which are the best-distributed bits of the Mersenne Twister output.
+    ///
+    /// # Arguments
+    /// * `buf` - The mutable buffer to fill with random values. Any type implementing [`Fillable`]
+    ///   can be used, including slices and arrays of `u8`, `u16`, `u32` and `u64`.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let mut buffer = [0u32; 10];
+    /// rng.fill(&mut buffer);
+    /// println!("Filled buffer: {:?}", buffer);
+    ///
+    /// let mut bytes = vec![0u8; 16];
+    /// rng.fill(&mut bytes[..]);
+    /// ```
+    pub fn fill<T: Fillable + ?Sized>(&mut self, buf: &mut T) {
+        buf.fill_from(self);
+    }
+
+    /// Generates a random integer of any primitive width within an inclusive range.
+    ///
+    /// This is a single generic entry point over every integer type, alongside concrete methods such as
+    /// [`Random::int`] and [`Random::uint`]. Values are drawn without modulo bias using rejection sampling,
+    /// from one `rand()` per attempt for spans of up to 2^32 values, two for spans up to 2^64, and four
+    /// beyond that.
+    ///
+    /// # Arguments
+    /// * `min` - The lower bound of the range (inclusive).
+    /// * `max` - The upper bound of the range (inclusive).
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let byte: u8 = rng.gen_int(0, 9);
+    /// let offset = rng.gen_int(-1_000_000_000_000i64, 1_000_000_000_000);
+    /// assert!(byte <= 9);
+    /// assert!((-1_000_000_000_000..=1_000_000_000_000).contains(&offset));
+    /// ```
+    ///
+    /// # Returns
+    /// A value of type `T` between `min` and `max`, inclusive, with every value equally likely.
+    ///
+    /// # Panics
+    /// Panics if `min` is greater than `max`.
+    pub fn gen_int<T: RandInt>(&mut self, min: T, max: T) -> T {
+        assert!(
+            min <= max,
+            "min must be less than or equal to max for gen_int"
+        );
+        let low = min.to_ordered();
+        let span = max.to_ordered() - low;
+        T::from_ordered(low + self.uniform_u128_inclusive(span))
+    }
+
+    /// Draws a value uniformly from `0..=span` with rejection sampling, using the narrowest draws that fit.
+    fn uniform_u128_inclusive(&mut self, span: u128) -> u128 {
+        if span <= u128::from(u32::MAX) {
+            let n = span as u64 + 1;
+            let limit = (1u64 << 32) / n * n;
+            loop {
+                let value = u64::from(self.rand());
+                if value < limit {
+                    return u128::from(value % n);
+                }
+            }
+        }
+        if span <= u128::from(u64::MAX) {
+            let n = span + 1;
+            let limit = (1u128 << 64) / n * n;
+            loop {
+                let value = u128::from(self.u64());
+                if value < limit {
+                    return value % n;
+                }
+            }
+        }
+        if span == u128::MAX {
+            return self.random_u128();
+        }
+        let n = span + 1;
+        // Values above `u128::MAX - 2^128 mod n` would map onto a partial final block.
+        let excess = (u128::MAX % n + 1) % n;
+        loop {
+            let value = self.random_u128();
+            if value <= u128::MAX - excess {
+                return value % n;
+            }
+        }
+    }
+
+    /// Shuffles the elements of a mutable slice randomly.
+    ///
+    /// # Arguments
+    /// * `slice` - The mutable slice to shuffle.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let mut values = [1, 2, 3, 4, 5];
+    /// rng.shuffle(&mut values);
+    /// println!("Shuffled values: {:?}", values);
+    /// ```
+    ///
+    /// # Notes
+    /// The permutation depends only on the seed and the slice length, so where each element ends up
+    /// depends on where it started. Use [`Random::shuffle_by_key`] when the result must not depend on the
+    /// initial order.
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = self.random_range(0, (i + 1) as u32) as usize;
+            slice.swap(i, j);
+        }
+    }
+
+    /// Returns the permutation that [`Random::shuffle`] would apply to a slice of length `n`.
+    ///
+    /// The draws are identical to shuffling a slice of that length, so element `k` of a shuffled slice is
+    /// element `indices[k]` of the original. Applying the same permutation to several parallel arrays keeps
+    /// them aligned, which separate `shuffle` calls would not.
+    ///
+    /// # Arguments
+    /// * `n` - The length of the slices to permute.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let names = ["a", "b", "c"];
+    /// let scores = [1, 2, 3];
+    /// let indices = rng.shuffle_indices(names.len());
+    /// let names: Vec<_> = indices.iter().map(|&i| names[i]).collect();
+    /// let scores: Vec<_> = indices.iter().map(|&i| scores[i]).collect();
+    /// println!("Shuffled pairs: {:?} {:?}", names, scores);
+    /// ```
+    ///
+    /// # Returns
+    /// A `Vec<usize>` containing a permutation of `0..n`.
+    pub fn shuffle_indices(&mut self, n: usize) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..n).collect();
+        self.shuffle(&mut indices);
+        indices
+    }
+
+    /// Shuffles the elements of a mutable slice into an order determined by the seed and the keys alone.
+    ///
+    /// The slice is first sorted by `key` and then shuffled with [`Random::shuffle`], so two slices holding
+    /// the same keyed records in different orders end up in the same order. This makes dataset shuffles
+    /// keyed by a record ID reproducible regardless of how the records were loaded.
+    ///
+    /// # Arguments
+    /// * `slice` - The mutable slice to shuffle.
+    /// * `key` - A function extracting the ordering key of an element.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut a = [(1, "a"), (2, "b"), (3, "c")];
+    /// let mut b = [(3, "c"), (1, "a"), (2, "b")];
+    /// Random::with_seed(42).shuffle_by_key(&mut a, |record| record.0);
+    /// Random::with_seed(42).shuffle_by_key(&mut b, |record| record.0);
+    /// assert_eq!(a, b);
+    /// ```
+    ///
+    /// # Notes
+    /// - The sort is stable, so elements with equal keys keep their relative input order before the
+    ///   shuffle. Keys should be unique for the result to be fully independent of the initial order.
+    /// - The draws are identical to those of [`Random::shuffle`] on a slice of the same length.
+    pub fn shuffle_by_key<T, K: Ord, F: Fn(&T) -> K>(
+        &mut self,
+        slice: &mut [T],
+        key: F,
+    ) {
+        slice.sort_by_key(key);
+        self.shuffle(slice);
+    }
+
+    /// Reverses a shuffle performed by [`Random::shuffle`] with an identically seeded generator.
+    ///
+    /// The swap indices of the forward Fisher-Yates shuffle are first replayed from this generator exactly
+    /// as `shuffle` draws them, and the swaps are then undone in reverse order. The generator must be in
+    /// the same state that the shuffling generator was in before it shuffled, and the slice must have the
+    /// same length.
+    ///
+    /// # Arguments
+    /// * `slice` - The shuffled slice to restore.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let original = [1, 2, 3, 4, 5];
+    /// let mut values = original;
+    /// Random::with_seed(42).shuffle(&mut values);
+    /// Random::with_seed(42).unshuffle(&mut values);
+    /// assert_eq!(values, original);
+    /// ```
+    pub fn unshuffle<T>(&mut self, slice: &mut [T]) {
+        let swaps: Vec<(usize, usize)> = (1..slice.len())
+            .rev()
+            .map(|i| (i, self.random_range(0, (i + 1) as u32) as usize))
+            .collect();
+        for &(i, j) in swaps.iter().rev() {
+            slice.swap(i, j);
+        }
+    }
+
+    /// Fills an uninitialized byte buffer with random data and returns it as an initialized slice.
+    ///
+    /// The bytes are identical to those written by `RngCore::fill_bytes` from the same state, so a caller
+    /// can skip zeroing a freshly allocated buffer before filling it.
+    ///
+    /// # Arguments
+    /// * `buf` - The uninitialized buffer to fill.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::mem::MaybeUninit;
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let mut buf = [MaybeUninit::<u8>::uninit(); 16];
+    /// let bytes = rng.fill_uninit(&mut buf);
+    /// assert_eq!(bytes.len(), 16);
+    /// ```
+    ///
+    /// # Returns
+    /// The same memory as `buf`, viewed as a fully initialized `&mut [u8]`.
+    ///
+    /// # Notes
+    /// - Only available with the `uninit` feature. The final conversion needs `unsafe`, so enabling the
+    ///   feature relaxes the crate-level `forbid(unsafe_code)` to `deny`, and only this method opts out.
+    /// - Bytes left pending by [`Random::discard_bytes`] are written first, as `fill_bytes` does.
+    #[cfg(feature = "uninit")]
+    #[allow(unsafe_code)]
+    pub fn fill_uninit<'a>(
+        &mut self,
+        buf: &'a mut [std::mem::MaybeUninit<u8>],
+    ) -> &'a mut [u8] {
+        let mut head = [0u8; 4];
+        let limit = buf.len().min(4);
+        let drained =
+            limit - self.drain_pending_bytes(&mut head[..limit]).len();
+        for (slot, &byte) in buf.iter_mut().zip(head[..drained].iter())
+        {
+            let _ = slot.write(byte);
+        }
+        for chunk in buf[drained..].chunks_mut(4) {
+            let bytes = self.rand().to_le_bytes();
+            for (slot, &byte) in chunk.iter_mut().zip(bytes.iter()) {
+                let _ = slot.write(byte);
+            }
+        }
+        // SAFETY: every element of `buf` was written by the loop above, and `MaybeUninit<u8>` has the
+        // same size and alignment as `u8`.
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                buf.as_mut_ptr().cast::<u8>(),
+                buf.len(),
+            )
+        }
+    }
+
+    /// Sends `count` random values into a Tokio channel, waiting whenever the channel is full.
+    ///
+    /// Each value is the next `rand()` output, so the stream is reproducible under a fixed seed. Awaiting
+    /// `send` on a bounded channel applies backpressure: the generator only runs ahead of the consumer by
+    /// the channel's capacity.
+    ///
+    /// # Arguments
+    /// * `tx` - The sending half of the channel. It is dropped when this method returns.
+    /// * `count` - The number of values to send.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// # tokio::runtime::Runtime::new().unwrap().block_on(async {
+    /// let (tx, mut rx) = tokio::sync::mpsc::channel(8);
+    /// let mut rng = Random::with_seed(42);
+    /// let producer = tokio::spawn(async move { rng.stream_to(tx, 100).await });
+    /// let mut received = 0;
+    /// while rx.recv().await.is_some() {
+    ///     received += 1;
+    /// }
+    /// producer.await.unwrap().unwrap();
+    /// assert_eq!(received, 100);
+    /// # });
+    /// ```
+    ///
+    /// # Returns
+    /// `Ok(())` once all `count` values have been sent.
+    ///
+    /// # Errors
+    /// Returns the `SendError` holding the unsent value if the receiver is dropped first. No values are
+    /// drawn after that one.
+    ///
+    /// # Notes
+    /// - Only available with the `async` feature.
+    #[cfg(feature = "async")]
+    pub async fn stream_to(
+        &mut self,
+        tx: tokio::sync::mpsc::Sender<u32>,
+        count: usize,
+    ) -> Result<(), tokio::sync::mpsc::error::SendError<u32>> {
+        for _ in 0..count {
+            tx.send(self.rand()).await?;
+        }
+        Ok(())
+    }
+
+    /// Advances the generator by `n` bytes of the `RngCore::fill_bytes` stream.
+    ///
+    /// Whole words are drawn and discarded, and when `n` is not a multiple of four the remaining bytes of
+    /// the last word are kept and returned first by the next `fill_bytes` call. Continuing with
+    /// `fill_bytes` after `discard_bytes(n)` therefore yields the same bytes as filling the whole stream and
+    /// dropping the first `n`, which is useful when resuming a saved byte stream.
+    ///
+    /// # Arguments
+    /// * `n` - The number of bytes to skip.
+    ///
+    /// # Examples
+    /// ```
+    /// use rand::RngCore;
+    /// use vrd::random::Random;
+    /// let mut whole = Random::with_seed(42);
+    /// let mut resumed = Random::with_seed(42);
+    /// let mut stream = [0u8; 16];
+    /// whole.fill_bytes(&mut stream);
+    /// resumed.discard_bytes(5);
+    /// let mut rest = [0u8; 11];
+    /// resumed.fill_bytes(&mut rest);
+    /// assert_eq!(&stream[5..], &rest);
+    /// ```
+    ///
+    /// # Notes
+    /// - A `fill_bytes` call that ends part-way through a word still discards the rest of that word, as
+    ///   before; only `discard_bytes` leaves a partial word pending.
+    /// - Seeding the generator clears any pending partial word.
+    pub fn discard_bytes(&mut self, n: usize) {
+        let mut n = n;
+        if self.pending_offset != 0 {
+            let available = 4 - usize::from(self.pending_offset);
+            if n < available {
+                self.pending_offset += n as u8;
+                return;
+            }
+            n -= available;
+            self.pending_offset = 0;
+        }
+        for _ in 0..n / 4 {
+            let _ = self.rand();
+        }
+        let remainder = n % 4;
+        if remainder > 0 {
+            self.pending_word = self.rand();
+            self.pending_offset = remainder as u8;
+        }
+    }
+
+    /// Copies the unused bytes of a word left pending by [`Random::discard_bytes`] and returns the rest of `dest`.
+    fn drain_pending_bytes<'a>(
+        &mut self,
+        dest: &'a mut [u8],
+    ) -> &'a mut [u8] {
+        if self.pending_offset == 0 {
+            return dest;
+        }
+        let bytes = self.pending_word.to_le_bytes();
+        let start = usize::from(self.pending_offset);
+        let take = (4 - start).min(dest.len());
+        let (head, tail) = dest.split_at_mut(take);
+        head.copy_from_slice(&bytes[start..start + take]);
+        self.pending_offset = ((start + take) % 4) as u8;
+        tail
+    }
+
+    /// Draws `n` bits from the generator's bit stream, packed most significant bit first.
+    ///
+    /// The bit stream reads each `rand()` output from its most significant bit down. Bits left over from a
+    /// word are buffered and used by the next `bits_be` or [`Random::bits_le`] call before another word is
+    /// drawn, so `bits_be(8)` followed by `bits_be(24)` returns the same bits as one `bits_be(32)`. The
+    /// first bit of the stream becomes bit `n - 1` of the result.
+    ///
+    /// # Arguments
+    /// * `n` - The number of bits to draw, at most 32.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::with_seed(42);
+    /// let word = Random::with_seed(42).rand();
+    /// let high = rng.bits_be(12);
+    /// let low = rng.bits_be(20);
+    /// assert_eq!((high << 20) | low, word);
+    /// ```
+    ///
+    /// # Notes
+    /// - Seeding the generator or calling [`Random::set_state`] discards any buffered bits.
+    /// - The bit stream is separate from the bytes left pending by [`Random::discard_bytes`].
+    ///
+    /// # Panics
+    /// Panics if `n` is greater than 32.
+    ///
+    /// # Returns
+    /// A `u32` whose low `n` bits hold the drawn bits and whose other bits are zero.
+    pub fn bits_be(&mut self, n: u8) -> u32 {
+        assert!(n <= 32, "n must be at most 32 for bits_be");
+        self.take_bits(n)
+    }
+
+    /// Draws `n` bits from the generator's bit stream, packed least significant bit first.
+    ///
+    /// This reads the same buffered bit stream as [`Random::bits_be`], but the first bit drawn becomes
+    /// bit `0` of the result, as wire formats that transmit the least significant bit first expect. For
+    /// the same generator state, `bits_le(n)` is `bits_be(n)` with its low `n` bits reversed.
+    ///
+    /// # Arguments
+    /// * `n` - The number of bits to draw, at most 32.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let be = Random::with_seed(42).bits_be(10);
+    /// let le = Random::with_seed(42).bits_le(10);
+    /// assert_eq!(le, be.reverse_bits() >> 22);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `n` is greater than 32.
+    ///
+    /// # Returns
+    /// A `u32` whose low `n` bits hold the drawn bits and whose other bits are zero.
+    pub fn bits_le(&mut self, n: u8) -> u32 {
+        assert!(n <= 32, "n must be at most 32 for bits_le");
+        if n == 0 {
+            return 0;
+        }
+        self.take_bits(n).reverse_bits() >> (32 - n)
+    }
+
+    /// Takes the next `n <= 32` bits of the buffered bit stream, first bit most significant.
+    fn take_bits(&mut self, n: u8) -> u32 {
+        if n == 0 {
+            return 0;
+        }
+        if n <= self.bit_count {
+            let bits = self.bit_word >> (32 - n);
+            self.bit_word =
+                self.bit_word.checked_shl(u32::from(n)).unwrap_or(0);
+            self.bit_count -= n;
+            return bits;
+        }
+        let buffered = if self.bit_count == 0 {
+            0
+        } else {
+            self.bit_word >> (32 - self.bit_count)
+        };
+        let needed = u32::from(n - self.bit_count);
+        let word = self.rand();
+        let bits = buffered.checked_shl(needed).unwrap_or(0)
+            | (word >> (32 - needed));
+        self.bit_word = word.checked_shl(needed).unwrap_or(0);
+        self.bit_count = 32 - needed as u8;
+        bits
+    }
+
+    /// Fills the given byte buffer with random data using the specified byte order.
+    ///
+    /// Each `rand()` output supplies four bytes in the requested order. A trailing partial chunk uses the
+    /// first bytes of one more output in that same order, and the rest of that output is discarded.
+    ///
+    /// # Arguments
+    /// * `buf` - The byte buffer to fill.
+    /// * `endian` - The order in which the bytes of each 32-bit output are written.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::{Endianness, Random};
+    /// let mut rng = Random::with_seed(42);
+    /// let mut buf = [0u8; 4];
+    /// rng.fill_bytes_with_endian(&mut buf, Endianness::Big);
+    /// assert_eq!(buf, Random::with_seed(42).rand().to_be_bytes());
+    /// ```
+    ///
+    /// # Notes
+    /// - `Endianness::Little` produces the same bytes as `RngCore::fill_bytes`, matching the `rand_core`
+    ///   convention followed by most Rust and NumPy Mersenne Twister implementations.
+    ///   Bytes left pending by [`Random::discard_bytes`] are written first, as `fill_bytes` does.
+    /// - `Endianness::Big` writes the most significant byte of each output first, matching implementations
+    ///   that serialize outputs in network byte order. The pending bytes belong to the little-endian byte
+    ///   stream, so this order drops them and starts at the next whole output.
+    pub fn fill_bytes_with_endian(
+        &mut self,
+        buf: &mut [u8],
+        endian: Endianness,
+    ) {
+        let buf = match endian {
+            Endianness::Little => self.drain_pending_bytes(buf),
+            Endianness::Big => {
+                self.pending_offset = 0;
+                buf
+            }
+        };
+        for chunk in buf.chunks_mut(4) {
+            let value = self.rand();
+            let bytes = match endian {
+                Endianness::Little => value.to_le_bytes(),
+                Endianness::Big => value.to_be_bytes(),
+            };
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    /// Runs the NIST SP 800-22 monobit (frequency) test over `bits` bits drawn from the generator.
+    ///
+    /// The bits are taken most-significant first from successive `rand()` outputs. The test statistic is the
+    /// normalized difference between the number of ones and zeros, `|ones - zeros| / sqrt(bits)`, and the
+    /// returned p-value is `erfc(statistic / sqrt(2))`.
+    ///
+    /// # Arguments
+    /// * `bits` - The number of bits to test. NIST recommends at least 100.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::with_seed(42);
+    /// let p_value = rng.monobit_test(100_000);
+    /// assert!(p_value >= 0.01);
+    /// ```
+    ///
+    /// # Returns
+    /// The p-value of the test, between 0.0 and 1.0. A p-value below `0.01` means the sequence fails the test
+    /// at the 1% significance level recommended by NIST.
+    ///
+    /// # Panics
+    /// Panics if `bits` is zero.
+    pub fn monobit_test(&mut self, bits: usize) -> f64 {
+        assert!(
+            bits > 0,
+            "bits must be greater than zero for monobit_test"
+        );
+        let mut ones: u64 = 0;
+        let mut remaining = bits;
+        while remaining > 0 {
+            let take = remaining.min(32);
+            let word = self.rand() >> (32 - take);
+            ones += u64::from(word.count_ones());
+            remaining -= take;
+        }
+        monobit_p_value(ones, bits as u64)
+    }
+
+    /// Draws `samples` outputs and summarizes their statistical quality in a single report.
+    ///
+    /// All metrics are computed from the same draws: the bytes of each output feed the chi-square and
+    /// entropy measures, and all 32 bits of each output feed the monobit test (see
+    /// [`Random::monobit_test`]). The report can be serialized with serde for archiving alongside results.
+    ///
+    /// # Arguments
+    /// * `samples` - The number of `rand()` outputs to draw.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::with_seed(42);
+    /// let report = rng.quality_report(100_000);
+    /// assert!(report.monobit_p_value >= 0.01);
+    /// println!("Byte entropy: {:.4} bits", report.byte_entropy);
+    /// ```
+    ///
+    /// # Returns
+    /// A [`QualityReport`] describing the drawn outputs.
+    ///
+    /// # Panics
+    /// Panics if `samples` is zero.
+    pub fn quality_report(&mut self, samples: usize) -> QualityReport {
+        assert!(
+            samples > 0,
+            "samples must be greater than zero for quality_report"
+        );
+        let mut counts = [0u64; 256];
+        let mut ones = 0u64;
+        let mut min = u32::MAX;
+        let mut max = u32::MIN;
+        for _ in 0..samples {
+            let word = self.rand();
+            for byte in word.to_le_bytes() {
+                counts[usize::from(byte)] += 1;
+            }
+            ones += u64::from(word.count_ones());
+            min = min.min(word);
+            max = max.max(word);
+        }
+        let total = samples as f64 * 4.0;
+        let expected = total / 256.0;
+        let mut chi_square = 0.0;
+        let mut byte_entropy = 0.0;
+        for &count in counts.iter() {
+            let observed = count as f64;
+            chi_square += (observed - expected).powi(2) / expected;
+            if count > 0 {
+                let p = observed / total;
+                byte_entropy -= p * math::log2(p);
+            }
+        }
+        QualityReport {
+            samples,
+            chi_square,
+            monobit_p_value: monobit_p_value(ones, samples as u64 * 32),
+            byte_entropy,
+            min,
+            max,
+        }
+    }
+
+    /// Estimates the mean and variance of a sampler by drawing from it `samples` times.
+    ///
+    /// The moments are accumulated with Welford's online algorithm, which updates a running mean and sum
+    /// of squared deviations instead of summing `x` and `x^2`. That avoids the catastrophic cancellation
+    /// of the textbook formula when the mean is large relative to the spread or the sample count runs
+    /// into the millions, and it needs no buffer for the samples.
+    ///
+    /// # Arguments
+    /// * `sampler` - A closure drawing one sample from the generator.
+    /// * `samples` - The number of samples to draw.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let (mean, variance) =
+    ///     rng.distribution_mean(|rng| rng.exponential(2.0), 100_000);
+    /// assert!((mean - 0.5).abs() < 0.01);
+    /// assert!((variance - 0.25).abs() < 0.02);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `samples` is zero.
+    ///
+    /// # Returns
+    /// A tuple of the empirical mean and the unbiased sample variance, which is `0.0` for one sample.
+    pub fn distribution_mean<F: FnMut(&mut Random) -> f64>(
+        &mut self,
+        mut sampler: F,
+        samples: usize,
+    ) -> (f64, f64) {
+        assert!(
+            samples > 0,
+            "samples must be greater than zero for distribution_mean"
+        );
+        let mut mean = 0.0;
+        let mut squared_deviations = 0.0;
+        for count in 1..=samples {
+            let x = sampler(self);
+            let delta = x - mean;
+            mean += delta / count as f64;
+            squared_deviations += delta * (x - mean);
+        }
+        let variance = if samples > 1 {
+            squared_deviations / (samples - 1) as f64
+        } else {
+            0.0
+        };
+        (mean, variance)
+    }
+
+    /// Serializes the generator state to a compact JSON string.
+    ///
+    /// The state array is encoded as 2496 little-endian bytes in base64, giving
+    /// `{"state":"<base64>","mti":N}`. This is less than half the size of the derived serde
+    /// representation while remaining valid, portable JSON. A partial word left by
+    /// [`Random::discard_bytes`] is stored as `"pending":[word,offset]` and buffered bits from
+    /// [`Random::bits_be`] as `"bits":[word,count]`, so a restored generator resumes both streams; the
+    /// keys are omitted when nothing is buffered.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let rng = Random::with_seed(42);
+    /// let json = rng.to_json_compact().unwrap();
+    /// let restored = Random::from_json_compact(&json).unwrap();
+    /// assert_eq!(rng, restored);
+    /// ```
+    ///
+    /// # Returns
+    /// A `Result` containing the compact JSON string.
+    ///
+    /// # Errors
+    /// Returns a `VrdError::GeneralError` if JSON serialization fails.
+    ///
+    /// # Notes
+    /// - The `params` field is not included, so generators using non-default parameters are restored with the default ones, and with the `counters` feature the
+    ///   draw counter restarts at zero.
+    pub fn to_json_compact(&self) -> Result<String, VrdError> {
+        let mut bytes = Vec::with_capacity(self.mt.len() * 4);
+        for word in self.mt.iter() {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        let compact = CompactState {
+            state: BASE64.encode(bytes),
+            mti: self.mti,
+            pending: (self.pending_offset != 0)
+                .then(|| (self.pending_word, self.pending_offset)),
+            bits: (self.bit_count != 0)
+                .then(|| (self.bit_word, self.bit_count)),
+        };
+        serde_json::to_string(&compact).map_err(|e| {
+            VrdError::GeneralError(format!(
+                "Failed to serialize compact state: {}",
+                e
+            ))
+        })
+    }
+
+    /// Restores a generator from the compact JSON form produced by [`Random::to_json_compact`].
+    ///
+    /// # Arguments
+    /// * `json` - A string in the form `{"state":"<base64>","mti":N}`, optionally with the `pending` and
+    ///   `bits` keys written by [`Random::to_json_compact`].
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::with_seed(42);
+    /// let json = rng.to_json_compact().unwrap();
+    /// let mut restored = Random::from_json_compact(&json).unwrap();
+    /// assert_eq!(rng.rand(), restored.rand());
+    /// ```
+    ///
+    /// # Returns
+    /// A `Result` containing the restored `Random` instance using the default parameters.
+    ///
+    /// # Errors
+    /// Returns a `VrdError::GeneralError` if the JSON is malformed, the state is not valid base64, the
+    /// decoded state is not exactly 2496 bytes, `mti` is greater than 625, the pending offset is not below
+    /// 4, or the buffered bit count is greater than 32.
+    pub fn from_json_compact(json: &str) -> Result<Random, VrdError> {
+        const N: usize = 624;
+        let compact: CompactState = serde_json::from_str(json)
+            .map_err(|e| {
+                VrdError::GeneralError(format!(
+                    "Failed to parse compact state: {}",
+                    e
+                ))
+            })?;
+        let bytes = BASE64.decode(compact.state).map_err(|e| {
+            VrdError::GeneralError(format!(
+                "Invalid base64 in compact state: {}",
+                e
+            ))
+        })?;
+        if bytes.len() != N * 4 {
+            return Err(VrdError::GeneralError(format!(
+                "Compact state must be {} bytes, got {}",
+                N * 4,
+                bytes.len()
+            )));
+        }
+        if compact.mti > UNSEEDED_MTI {
+            return Err(VrdError::GeneralError(format!(
+                "Compact state index {} is out of range",
+                compact.mti
+            )));
+        }
+        let mut rng = Random::blank(MersenneTwisterParams::default());
+        for (word, chunk) in rng.mt.iter_mut().zip(bytes.chunks(4)) {
+            *word = u32::from_le_bytes([
+                chunk[0], chunk[1], chunk[2], chunk[3],
+            ]);
+        }
+        rng.mti = compact.mti;
+        if let Some((word, offset)) = compact.pending {
+            if offset >= 4 {
+                return Err(VrdError::GeneralError(format!(
+                    "Compact state pending offset {} is out of range",
+                    offset
+                )));
+            }
+            rng.pending_word = word;
+            rng.pending_offset = offset;
+        }
+        if let Some((word, count)) = compact.bits {
+            if count > 32 {
+                return Err(VrdError::GeneralError(format!(
+                    "Compact state bit count {} is out of range",
+                    count
+                )));
+            }
+            rng.bit_word = word;
+            rng.bit_count = count;
+        }
+        Ok(rng)
+    }
+
+    /// Returns a copy of this generator moved to a normalized position without changing its future output.
+    ///
+    /// A generator that is due to twist is twisted (and seeded with the default seed first if it was never
+    /// seeded), leaving it at `mti == 0`. Any other generator is already in canonical form. Two generators
+    /// with the same continuation therefore have equal canonical forms, which makes the derived `Eq`, `Ord`
+    /// and `Hash` implementations meaningful for sorting and deduplication.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let due = Random::with_seed(42);
+    /// let mut twisted = due.clone();
+    /// twisted.twist();
+    /// assert_ne!(due, twisted);
+    /// assert_eq!(due.canonical(), twisted.canonical());
+    /// ```
+    ///
+    /// # Returns
+    /// A new `Random` instance producing the same sequence as `self`.
+    pub fn canonical(&self) -> Random {
+        const N: usize = 624;
+        let mut rng = self.clone();
+        if rng.mti >= N {
+            if rng.is_uninitialized() {
+                rng.seed(DEFAULT_SEED);
+            }
+            rng.twist();
+        }
+        rng
+    }
+
+    /// Returns `true` if clones of `self` and `other` produce the same next `n` outputs.
+    ///
+    /// Neither generator is advanced. Unlike `==`, which compares the raw state and so tells apart a
+    /// generator that is due to twist from the same generator just after the twist, this compares what
+    /// the generators actually emit, which is usually what a reproducibility test needs. Agreement on a
+    /// finite prefix does not prove the streams are identical forever, so pick `n` to cover the draws the
+    /// test cares about.
+    ///
+    /// # Arguments
+    /// * `other` - The generator to compare against.
+    /// * `n` - The number of upcoming `rand()` outputs to compare.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let due = Random::with_seed(42);
+    /// let mut twisted = due.clone();
+    /// twisted.twist();
+    /// assert_ne!(due, twisted);
+    /// assert!(due.produces_same_as(&twisted, 1000));
+    /// ```
+    pub fn produces_same_as(&self, other: &Random, n: usize) -> bool {
+        let mut left = self.clone();
+        let mut right = other.clone();
+        (0..n).all(|_| left.rand() == right.rand())
+    }
+
+    /// Returns a hash of the sequence this generator will produce, ignoring how its state is laid out.
+    ///
+    /// The derived `Hash` implementation feeds all 624 state words, `mti` and `params` to the hasher, and
+    /// treats a generator that is due to twist as different from the same generator after the twist. This
+    /// method instead hashes the [`Random::canonical`] form, so generators with the same continuation hash
+    /// equally. Only `mti`, the parameters and the next eight state words are hashed, so a generator that is
+    /// not due to twist is hashed without touching the rest of the array.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let due = Random::with_seed(42);
+    /// let mut twisted = due.clone();
+    /// twisted.twist();
+    /// assert_eq!(due.sequence_hash(), twisted.sequence_hash());
+    /// ```
+    ///
+    /// # Returns
+    /// A 64-bit FNV-1a hash of the canonical state. Generators with different continuations can collide,
+    /// so treat equal hashes as a hint and confirm with `canonical()` comparisons.
+    pub fn sequence_hash(&self) -> u64 {
+        const N: usize = 624;
+        const HASHED_WORDS: usize = 8;
+        let twisted;
+        let rng = if self.mti >= N {
+            twisted = self.canonical();
+            &twisted
+        } else {
+            self
+        };
+        let params = rng.params;
+        let mut bytes = Vec::with_capacity((HASHED_WORDS + 6) * 4);
+        for word in [
+            rng.mti as u32,
+            params.matrix_a,
+            params.upper_mask,
+            params.lower_mask,
+            params.tempering_mask_b,
+            params.tempering_mask_c,
+        ] {
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        for offset in 0..HASHED_WORDS {
+            let word = rng.mt[(rng.mti + offset) % N];
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        fnv1a_64(&bytes)
+    }
+
+    /// Compares the internal state with that of another generator and reports the first difference.
+    ///
+    /// This is a debugging aid for reproducibility investigations: when two generators that are expected
+    /// to be identical start producing different numbers, it pinpoints where their states diverge.
+    ///
+    /// # Arguments
+    /// * `other` - The generator to compare against.
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::{Random, StateDiff};
+    /// let mut rng1 = Random::new();
+    /// let mut rng2 = Random::new();
+    /// rng1.seed(42);
+    /// rng2.seed(42);
+    /// assert_eq!(rng1.state_diff(&rng2), None);
+    ///
+    /// rng2.set_mti(100);
+    /// assert_eq!(
+    ///     rng1.state_diff(&rng2),
+    ///     Some(StateDiff::IndexOnly { self_mti: 624, other_mti: 100 })
+    /// );
+    ///
+    /// rng2.mt[10] ^= 1;
+    /// assert_eq!(
+    ///     rng1.state_diff(&rng2),
+    ///     Some(StateDiff::Word { index: 10, self_value: rng1.mt[10], other_value: rng2.mt[10] })
+    /// );
+    /// ```
+    ///
+    /// # Returns
+    /// `Some(StateDiff::Word { .. })` for the first index at which the `mt` arrays differ,
+    /// `Some(StateDiff::IndexOnly { .. })` if the arrays match but the `mti` values do not, or `None` if
+    /// both the arrays and the indices are identical.
+    pub fn state_diff(&self, other: &Random) -> Option<StateDiff> {
+        if let Some(index) = self
+            .mt
+            .iter()
+            .zip(other.mt.iter())
+            .position(|(a, b)| a != b)
+        {
+            return Some(StateDiff::Word {
+                index,
+                self_value: self.mt[index],
+                other_value: other.mt[index],
+            });
+        }
+        if self.mti != other.mti {
+            return Some(StateDiff::IndexOnly {
+                self_mti: self.mti,
+                other_mti: other.mti,
+            });
+        }
+        None
+    }
+
+    /// Samples an index from an arbitrary discrete probability mass function.
+    ///
+    /// The `sample_discrete` method draws a single uniform value and walks the cumulative sum of `pmf`,
+    /// returning the first index whose cumulative probability exceeds the draw.
+    ///
+    /// # Arguments
+    /// * `pmf` - A slice of probabilities, one per outcome. Each value must be non-negative, and the
+    ///   values must sum to 1.0 (within a tolerance of `1e-6`).
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let pmf = [0.2, 0.5, 0.3];
+    /// let index = rng.sample_discrete(&pmf);
+    /// assert!(index < pmf.len());
+    /// ```
+    ///
+    /// # Returns
+    /// A `usize` index into `pmf`, chosen with probability `pmf[index]`.
+    ///
+    /// # Panics
+    /// Panics if `pmf` is empty, contains a negative or `NaN` probability, or does not sum to 1.0.
+    pub fn sample_discrete(&mut self, pmf: &[f64]) -> usize {
+        assert!(
+            !pmf.is_empty(),
+            "pmf must not be empty for sample_discrete"
+        );
+        assert!(
+            pmf.iter().all(|&p| p >= 0.0),
+            "pmf probabilities must be non-negative for sample_discrete"
+        );
+        let total: f64 = pmf.iter().sum();
+        assert!(
+            (total - 1.0).abs() <= PMF_TOLERANCE,
+            "pmf probabilities must sum to 1.0 for sample_discrete"
+        );
+
+        let u = self.f64();
+        let mut cumulative = 0.0;
+        for (index, &p) in pmf.iter().enumerate() {
+            cumulative += p;
+            if u < cumulative {
+                return index;
+            }
+        }
+        // Rounding can leave the cumulative sum just below the draw; fall back to the last possible outcome.
+        pmf.iter().rposition(|&p| p > 0.0).unwrap_or(pmf.len() - 1)
+    }
+
+    /// Samples an index from a precomputed cumulative distribution function.
+    ///
+    /// The `choose_from_cdf` method draws a single uniform value and binary-searches `cdf` for the first
+    /// index whose cumulative probability exceeds the draw. For the same generator state it returns the same
+    /// index as `sample_discrete` on the corresponding probability mass function, but in `O(log n)` time and
+    /// without re-summing the probabilities.
+    ///
+    /// # Arguments
+    /// * `cdf` - A non-decreasing slice of cumulative probabilities, one per outcome. Each value must be
+    ///   non-negative, and the last value must be 1.0 (within a tolerance of `1e-6`).
+    ///
+    /// # Examples
+    /// ```
+    /// use vrd::random::Random;
+    /// let mut rng = Random::new();
+    /// let cdf = [0.2, 0.7, 1.0];
+    /// let index = rng.choose_from_cdf(&cdf);
+    /// assert!(index < cdf.len());
+    /// ```
+    ///
+    /// # Returns
+    /// A `usize` index into `cdf`, chosen with probability `cdf[index] - cdf[index - 1]`.
+    ///
+    /// # Panics
+    /// Panics if `cdf` is empty, contains a negative or `NaN` value, is not sorted, or does not end at 1.0.
+    pub fn choose_from_cdf(&mut self, cdf: &[f64]) -> usize {
+        assert!(
+            !cdf.is_empty(),
+            "cdf must not be empty for choose_from_cdf"
+        );
+        assert!(
+            cdf[0] >= 0.0
+                && cdf.windows(2).all(|pair| pair[0] <= pair[1]),
+            "cdf must be non-negative and sorted for choose_from_cdf"
+        );
+        let last = cdf[cdf.len() - 1];
+        assert!(
+            (last - 1.0).abs() <= PMF_TOLERANCE,
+            "cdf must end at 1.0 for choose_from_cdf"
+        );
+
+        let u = self.f64();
+        let index = cdf.partition_point(|&c| c <= u);
+        if index < cdf.len() {
+            index
+        } else {
+            // The draw is at or above a final value just below 1.0; use the first outcome that reaches it.
+            cdf.partition_point(|&c| c < last)
+        }
+    }
+}
+
+/// Inverts `y ^= y >> shift`, recovering `shift` more of the high bits on each pass.
+fn undo_right_shift_xor(y: u32, shift: u32) -> u32 {
+    let mut x = y;
+    for _ in 0..32 / shift {
+        x = y ^ (x >> shift);
+    }
+    x
+}
+
+/// Inverts `y ^= (y << shift) & mask`, recovering `shift` more of the low bits on each pass.
+fn undo_left_shift_xor(y: u32, shift: u32, mask: u32) -> u32 {
+    let mut x = y;
+    for _ in 0..32 / shift {
+        x = y ^ ((x << shift) & mask);
+    }
+    x
+}
+
+/// The largest trial count for which `bernoulli_trials` runs every trial individually.
+const BERNOULLI_DIRECT_MAX_TRIALS: u32 = 64;
+
+/// The mean at and above which Poisson sampling switches from Knuth's method to PTRS.
+pub(crate) const POISSON_PTRS_THRESHOLD: f64 = 10.0;
+
+/// Precomputed constants for Hörmann's transformed rejection Poisson sampler (PTRS).
+///
+/// See W. Hörmann, "The transformed rejection method for generating Poisson random variables",
+/// Insurance: Mathematics and Economics 12 (1993). The method is valid for means of 10 and above.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct PoissonPtrs {
+    lam: f64,
+    log_lam: f64,
+    a: f64,
+    b: f64,
+    inv_alpha: f64,
+    v_r: f64,
+}
+
+impl PoissonPtrs {
+    pub(crate) fn new(lam: f64) -> Self {
+        let b = 0.931 + 2.53 * math::sqrt(lam);
+        PoissonPtrs {
+            lam,
+            log_lam: math::ln(lam),
+            a: -0.059 + 0.02483 * b,
+            b,
+            inv_alpha: 1.1239 + 1.1328 / (b - 3.4),
+            v_r: 0.9277 - 3.6224 / (b - 2.0),
+        }
+    }
+
+    pub(crate) fn sample(&self, rng: &mut Random) -> u64 {
+        loop {
+            let u = rng.f64() - 0.5;
+            let v = rng.f64();
+            let us = 0.5 - u.abs();
+            let k =
+                ((2.0 * self.a / us + self.b) * u + self.lam + 0.43)
+                    .floor();
+            if us >= 0.07 && v <= self.v_r {
+                return k as u64;
+            }
+            if k < 0.0 || (us < 0.013 && v > us) {
+                continue;
+            }
+            if math::ln(v) + math::ln(self.inv_alpha)
+                - math::ln(self.a / (us * us) + self.b)
+                <= -self.lam + k * self.log_lam - ln_gamma(k + 1.0)
+            {
+                return k as u64;
+            }
+        }
+    }
+}
+
+/// The number of words tempered together by the `simd` fill path.
+#[cfg(all(
+    feature = "simd",
+    any(target_arch = "x86_64", target_arch = "aarch64")
+))]
+const TEMPER_LANES: usize = 8;
+
+/// Applies the Mersenne Twister tempering transform to every lane, four lanes per SSE2 register.
+///
+/// SSE2 is part of the x86_64 baseline, so no runtime feature detection is needed. The `simd` feature
+/// relaxes the crate-level `forbid(unsafe_code)` to `deny`, and only the two lane functions opt out.
+#[cfg(all(feature = "simd", target_arch = "x86_64"))]
+#[allow(unsafe_code)]
+fn temper_lanes(
+    lanes: &mut [u32; TEMPER_LANES],
+    params: MersenneTwisterParams,
+) {
+    use std::arch::x86_64::{
+        __m128i, _mm_and_si128, _mm_loadu_si128, _mm_set1_epi32,
+        _mm_slli_epi32, _mm_srli_epi32, _mm_storeu_si128,
+        _mm_xor_si128,
+    };
+    for chunk in lanes.chunks_exact_mut(4) {
+        // SAFETY: SSE2 is always available on x86_64, and the unaligned load and store touch exactly the
+        // four `u32` values of `chunk`.
+        unsafe {
+            let mask_b = _mm_set1_epi32(params.tempering_mask_b as i32);
+            let mask_c = _mm_set1_epi32(params.tempering_mask_c as i32);
+            let mut y =
+                _mm_loadu_si128(chunk.as_ptr().cast::<__m128i>());
+            y = _mm_xor_si128(y, _mm_srli_epi32(y, 11));
+            y = _mm_xor_si128(
+                y,
+                _mm_and_si128(_mm_slli_epi32(y, 7), mask_b),
+            );
+            y = _mm_xor_si128(
+                y,
+                _mm_and_si128(_mm_slli_epi32(y, 15), mask_c),
+            );
+            y = _mm_xor_si128(y, _mm_srli_epi32(y, 18));
+            _mm_storeu_si128(chunk.as_mut_ptr().cast::<__m128i>(), y);
+        }
+    }
+}
+
+/// Applies the Mersenne Twister tempering transform to every lane, four lanes per NEON register.
+///
+/// NEON is part of the aarch64 baseline, so no runtime feature detection is needed. The NEON intrinsics
+/// were stabilized in Rust 1.59, so the `simd` feature on aarch64 needs that compiler or newer.
+#[cfg(all(feature = "simd", target_arch = "aarch64"))]
+#[allow(unsafe_code, clippy::incompatible_msrv)]
+fn temper_lanes(
+    lanes: &mut [u32; TEMPER_LANES],
+    params: MersenneTwisterParams,
+) {
+    use std::arch::aarch64::{
+        vandq_u32, vdupq_n_u32, veorq_u32, vld1q_u32, vshlq_n_u32,
+        vshrq_n_u32, vst1q_u32,
+    };
+    for chunk in lanes.chunks_exact_mut(4) {
+        // SAFETY: NEON is always available on aarch64, and the load and store touch exactly the four
+        // `u32` values of `chunk`.
+        unsafe {
+            let mask_b = vdupq_n_u32(params.tempering_mask_b);
+            let mask_c = vdupq_n_u32(params.tempering_mask_c);
+            let mut y = vld1q_u32(chunk.as_ptr());
+            y = veorq_u32(y, vshrq_n_u32(y, 11));
+            y = veorq_u32(y, vandq_u32(vshlq_n_u32(y, 7), mask_b));
+            y = veorq_u32(y, vandq_u32(vshlq_n_u32(y, 15), mask_c));
+            y = veorq_u32(y, vshrq_n_u32(y, 18));
+            vst1q_u32(chunk.as_mut_ptr(), y);
+        }
+    }
+}
+
+/// An item held in the reservoir of [`Random::weighted_reservoir`], ordered so that the smallest key is
+/// at the top of a `BinaryHeap`.
+struct ReservoirEntry<T> {
+    key: f64,
+    item: T,
+}
+
+impl<T> PartialEq for ReservoirEntry<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<T> Eq for ReservoirEntry<T> {}
+
+impl<T> PartialOrd for ReservoirEntry<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for ReservoirEntry<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.key.partial_cmp(&self.key).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// The JSON shape used by [`Random::to_json_compact`] and [`Random::from_json_compact`].
+#[derive(Serialize, Deserialize)]
+struct CompactState {
+    state: String,
+    mti: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pending: Option<(u32, u8)>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bits: Option<(u32, u8)>,
+}
+
+/// The offset basis of the 64-bit FNV-1a hash.
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+
+/// Computes the 64-bit FNV-1a hash of `bytes`.
+fn fnv1a_64(bytes: &[u8]) -> u64 {
+    fnv1a_64_extend(FNV_OFFSET_BASIS, bytes)
+}
+
+/// Continues a 64-bit FNV-1a hash from `hash` over `bytes`.
+fn fnv1a_64_extend(hash: u64, bytes: &[u8]) -> u64 {
+    const FNV_PRIME: u64 = 0x100000001b3;
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+/// A 64-bit FNV-1a `Hasher` that writes integers in little-endian order, used by
+/// [`Random::from_hashable`].
+struct Fnv1aHasher(u64);
+
+impl Hasher for Fnv1aHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0 = fnv1a_64_extend(self.0, bytes);
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+}
+
+/// Fills `out` with words mixed from `seeds` following the `std::seed_seq::generate` algorithm.
+fn seed_seq_generate(seeds: &[u32], out: &mut [u32]) {
+    let n = out.len();
+    if n == 0 {
+        return;
+    }
+    out.fill(0x8b8b8b8b);
+    let s = seeds.len();
+    let t = if n >= 623 {
+        11
+    } else if n >= 68 {
+        7
+    } else if n >= 39 {
+        5
+    } else if n >= 7 {
+        3
+    } else {
+        (n - 1) / 2
+    };
+    let p = (n - t) / 2;
+    let q = p + t;
+    let m = (s + 1).max(n);
+    let mix = |x: u32| x ^ (x >> 27);
+    for k in 0..m {
+        let r1 = 1664525u32.wrapping_mul(mix(out[k % n]
+            ^ out[(k + p) % n]
+            ^ out[(k + n - 1) % n]));
+        let r2 = if k == 0 {
+            r1.wrapping_add(s as u32)
+        } else if k <= s {
+            r1.wrapping_add((k % n) as u32).wrapping_add(seeds[k - 1])
+        } else {
+            r1.wrapping_add((k % n) as u32)
+        };
+        out[(k + p) % n] = out[(k + p) % n].wrapping_add(r1);
+        out[(k + q) % n] = out[(k + q) % n].wrapping_add(r2);
+        out[k % n] = r2;
+    }
+    for k in m..m + n {
+        let r3 = 1566083941u32.wrapping_mul(mix(out[k % n]
+            .wrapping_add(out[(k + p) % n])
+            .wrapping_add(out[(k + n - 1) % n])));
+        let r4 = r3.wrapping_sub((k % n) as u32);
+        out[(k + p) % n] ^= r3;
+        out[(k + q) % n] ^= r4;
+        out[k % n] = r4;
+    }
+}
+
+/// Computes the natural logarithm of the gamma function for `x > 0` using a Stirling series.
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 10] = [
+        8.333333333333333e-02,
+        -2.777777777777778e-03,
+        7.936507936507937e-04,
+        -5.952380952380952e-04,
+        8.417508417508418e-04,
+        -1.917526917526918e-03,
+        6.41025641025641e-03,
+        -2.955065359477124e-02,
+        1.796443723688307e-01,
+        -1.3924322169059e+00,
+    ];
+    if x == 1.0 || x == 2.0 {
+        return 0.0;
+    }
+    // Shift small arguments up so the asymptotic series is accurate, then undo the shift.
+    let shift = if x < 7.0 { (7.0 - x).floor() } else { 0.0 };
+    let mut x0 = x + shift;
+    let x2 = 1.0 / (x0 * x0);
+    let mut series = COEFFICIENTS[9];
+    for &c in COEFFICIENTS[..9].iter().rev() {
+        series = series * x2 + c;
+    }
+    let mut result = series / x0
+        + 0.5 * math::ln(2.0 * std::f64::consts::PI)
+        + (x0 - 0.5) * math::ln(x0)
+        - x0;
+    for _ in 0..shift as u32 {
+        x0 -= 1.0;
+        result -= math::ln(x0);
+    }
+    result
+}
+
+/// Computes the complementary error function with a fractional error below `1.2e-7`.
+///
+/// This is the Chebyshev approximation from Press et al., "Numerical Recipes", section 6.2.
+fn erfc(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 10] = [
+        -1.26551223,
+        1.00002368,
+        0.37409196,
+        0.09678418,
+        -0.18628806,
+        0.27886807,
+        -1.13520398,
+        1.48851587,
+        -0.82215223,
+        0.17087277,
+    ];
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let mut polynomial = 0.0;
+    for &c in COEFFICIENTS.iter().rev() {
+        polynomial = polynomial * t + c;
+    }
+    let result = t * math::exp(-z * z + polynomial);
+    if x >= 0.0 {
+        result
+    } else {
+        2.0 - result
+    }
+}
+
+/// Returns the NIST monobit p-value for `ones` set bits out of `bits` bits.
+fn monobit_p_value(ones: u64, bits: u64) -> f64 {
+    let sum = 2.0 * ones as f64 - bits as f64;
+    let statistic = sum.abs() / math::sqrt(bits as f64);
+    erfc(statistic / std::f64::consts::SQRT_2)
+}
+
+/// A summary of the statistical quality of a generator's output, produced by [`Random::quality_report`].
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct QualityReport {
+    /// The number of `rand()` outputs the report was computed from.
+    pub samples: usize,
+    /// The chi-square statistic of the byte histogram against a uniform distribution, with 255 degrees
+    /// of freedom. Values near 255 indicate uniform bytes.
+    pub chi_square: f64,
+    /// The p-value of the NIST monobit test over all drawn bits. Values below `0.01` fail the test.
+    pub monobit_p_value: f64,
+    /// The Shannon entropy of the byte histogram in bits per byte, at most `8.0`.
+    pub byte_entropy: f64,
+    /// The smallest output observed.
+    pub min: u32,
+    /// The largest output observed.
+    pub max: u32,
+}
+
+/// The first difference between two generator states, as reported by [`Random::state_diff`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum StateDiff {
+    /// The `mt` arrays differ, first at `index`.
+    Word {
+        /// The first index at which the arrays differ.
+        index: usize,
+        /// The word at `index` in the generator `state_diff` was called on.
+        self_value: u32,
+        /// The word at `index` in the generator passed to `state_diff`.
+        other_value: u32,
+    },
+    /// The `mt` arrays match but the positions within them differ, so the next outputs still differ.
+    IndexOnly {
+        /// The `mti` of the generator `state_diff` was called on.
+        self_mti: usize,
+        /// The `mti` of the generator passed to `state_diff`.
+        other_mti: usize,
+    },
+}
+
+/// The byte order used when converting 32-bit outputs into bytes.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Endianness {
+    /// Least significant byte first.
+    Little,
+    /// Most significant byte first.
+    Big,
+}
+
+/// The alphabet used by [`Random::string_with`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum StringCharset {
+    /// Digits and ASCII letters of both cases (`0-9a-zA-Z`).
+    Alphanumeric,
+    /// Lowercase ASCII letters (`a-z`).
+    Lowercase,
+    /// Lowercase hexadecimal digits (`0-9a-f`).
+    Hex,
+    /// The URL-safe Base64 alphabet (`A-Za-z0-9-_`).
+    Base64Url,
+    /// A caller-supplied alphabet. Repeated characters are proportionally more likely.
+    Custom(Vec<char>),
+}
+
+impl StringCharset {
+    /// Returns the characters of the alphabet in a fixed order.
+    fn alphabet(&self) -> Vec<char> {
+        let ascii: &[u8] = match self {
+            StringCharset::Alphanumeric => {
+                b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ"
+            }
+            StringCharset::Lowercase => b"abcdefghijklmnopqrstuvwxyz",
+            StringCharset::Hex => b"0123456789abcdef",
+            StringCharset::Base64Url => {
+                b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"
+            }
+            StringCharset::Custom(chars) => return chars.clone(),
+        };
+        ascii.iter().map(|&byte| char::from(byte)).collect()
+    }
+}
+
+/// A buffer of integers that can be filled with random values by [`Random::fill`].
+///
+/// This trait is implemented for slices of `u8`, `u16`, `u32` and `u64`, and for fixed-size arrays of
+/// those types.
+pub trait Fillable {
+    /// Fills `self` with random values drawn from `rng`.
+    fn fill_from(&mut self, rng: &mut Random);
+}
+
+impl Fillable for [u8] {
+    fn fill_from(&mut self, rng: &mut Random) {
+        for item in self.iter_mut() {
+            *item = (rng.rand() >> 24) as u8;
+        }
+    }
+}
+
+impl Fillable for [u16] {
+    fn fill_from(&mut self, rng: &mut Random) {
+        for item in self.iter_mut() {
+            *item = (rng.rand() >> 16) as u16;
+        }
+    }
+}
+
+impl Fillable for [u32] {
+    fn fill_from(&mut self, rng: &mut Random) {
+        for item in self.iter_mut() {
+            *item = rng.rand();
+        }
+    }
+}
+
+impl Fillable for [u64] {
+    fn fill_from(&mut self, rng: &mut Random) {
+        for item in self.iter_mut() {
+            *item = rng.u64();
+        }
+    }
+}
+
+impl<T, const N: usize> Fillable for [T; N]
+where
+    [T]: Fillable,
+{
+    fn fill_from(&mut self, rng: &mut Random) {
+        self[..].fill_from(rng);
+    }
+}
+
+/// A generator that yields one interleaved subsequence of another generator's stream.
+///
+/// Created by [`Random::leapfrog`]. After every output the wrapped generator skips the state words that
+/// belong to the other subsequences, so the stride is carried by this type rather than by `Random`. Clones,
+/// comparisons and serialized forms include the stride, so saving and restoring a `Leapfrog` resumes the
+/// same subsequence.
+///
+/// # Examples
+/// ```
+/// use vrd::random::Random;
+/// let mut master = Random::with_seed(42);
+/// let mut parts = Random::leapfrog(&master, 3);
+/// let first: Vec<u32> = parts[0].by_ref().take(2).collect();
+/// let expected = [master.rand(), {
+///     let _ = master.rand();
+///     let _ = master.rand();
+///     master.rand()
+/// }];
+/// assert_eq!(first, expected);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct Leapfrog {
+    /// The wrapped generator, positioned at the next word of this subsequence.
+    inner: Random,
+    /// The number of state words skipped after every output.
+    skip: usize,
+}
+
+impl Leapfrog {
+    /// Splits `master`, which already skips `skip` words after every output, into `parts` generators.
+    fn split(
+        master: &Random,
+        skip: usize,
+        parts: usize,
+    ) -> Vec<Leapfrog> {
+        assert!(parts > 0, "parts must be positive for leapfrog");
+        let step = skip + 1;
+        let stride = parts
+            .checked_mul(step)
+            .expect("stride must fit in a usize for leapfrog");
+        let mut start = master.clone();
+        if start.is_uninitialized() {
+            start.seed(DEFAULT_SEED);
+        }
+        (0..parts)
+            .map(|i| {
+                let mut inner = start.clone();
+                inner.skip_words(i * step);
+                Leapfrog {
+                    inner,
+                    skip: stride - 1,
+                }
+            })
+            .collect()
     }
 
-    /// Generates a random 64-bit floating-point number in the range [0.0, 1.0).
+    /// Generates the next output of this subsequence.
     ///
     /// # Examples
     /// ```
     /// use vrd::random::Random;
-    /// let mut rng = Random::new();
-    /// let random_f64 = rng.f64();
-    /// println!("Random f64: {}", random_f64);
+    /// let mut master = Random::with_seed(42);
+    /// let mut parts = Random::leapfrog(&master, 2);
+    /// assert_eq!(parts[0].rand(), master.rand());
     /// ```
     ///
     /// # Returns
-    /// An `f64` representing a randomly generated 64-bit floating-point number.
-    pub fn f64(&mut self) -> f64 {
-        self.double()
+    /// A `u32` equal to the master output at the current position of this subsequence.
+    pub fn rand(&mut self) -> u32 {
+        let output = self.inner.rand();
+        self.inner.skip_words(self.skip);
+        output
     }
 
-    /// Generates a random string of the specified length.
-    ///
-    /// # Arguments
-    /// * `length` - The desired length of the random string.
-    ///
-    /// # Examples
-    /// ```
-    /// use vrd::random::Random;
-    /// let mut rng = Random::new();
-    /// let random_string = rng.string(10);
-    /// println!("Random string: {}", random_string);
-    /// ```
-    ///
-    /// # Returns
-    /// A `String` representing a randomly generated string of the specified length.
-    pub fn string(&mut self, length: usize) -> String {
-        (0..length).map(|_| self.char()).collect()
+    /// Returns the distance between consecutive outputs of this subsequence in the master stream.
+    pub fn stride(&self) -> usize {
+        self.skip + 1
     }
 
-    /// Generates a random number from a standard normal distribution (mean = 0, stddev = 1).
+    /// Splits this subsequence again into `parts` interleaved subsequences.
+    ///
+    /// Generator `i` of the result produces outputs `i`, `i + parts`, ... of this generator's stream, so
+    /// each has a stride of `parts` times this one.
     ///
     /// # Arguments
-    /// * `mu` - The mean of the normal distribution.
-    /// * `sigma` - The standard deviation of the normal distribution.
+    /// * `parts` - The number of generators to produce.
     ///
     /// # Examples
     /// ```
     /// use vrd::random::Random;
-    /// let mut rng = Random::new();
-    /// let normal = rng.normal(0.0, 1.0);
-    /// println!("Random number from standard normal distribution: {}", normal);
+    /// let master = Random::with_seed(42);
+    /// let halves = Random::leapfrog(&master, 2);
+    /// let quarters = halves[0].leapfrog(2);
+    /// assert_eq!(quarters[0].stride(), 4);
     /// ```
     ///
-    /// # Returns
-    /// An `f64` representing a random number from a standard normal distribution.
-    pub fn normal(&mut self, mu: f64, sigma: f64) -> f64 {
-        let u1 = self.f64();
-        let u2 = self.f64();
-        let z0 = (-2.0 * u1.ln()).sqrt()
-            * (2.0 * std::f64::consts::PI * u2).cos();
-        mu + sigma * z0
+    /// # Panics
+    /// Panics if `parts` is zero, or if the combined stride does not fit in a `usize`.
+    pub fn leapfrog(&self, parts: usize) -> Vec<Leapfrog> {
+        Leapfrog::split(&self.inner, self.skip, parts)
     }
 
-    /// Generates a random number from an exponential distribution with the specified rate parameter.
-    ///
-    /// # Arguments
-    /// * `rate` - The rate parameter (lambda) of the exponential distribution.
-    ///
-    /// # Examples
-    /// ```
-    /// use vrd::random::Random;
-    /// let mut rng = Random::new();
-    /// let exponential = rng.exponential(1.5);
-    /// println!("Random number from exponential distribution with rate 1.5: {}", exponential);
-    /// ```
+    /// Returns the wrapped generator, discarding the stride.
     ///
-    /// # Returns
-    /// An `f64` representing a random number from an exponential distribution.
-    pub fn exponential(&mut self, rate: f64) -> f64 {
-        -1.0 / rate * (1.0 - self.f64()).ln()
+    /// The returned `Random` continues the master stream word by word from this subsequence's next
+    /// position, so it no longer skips the other subsequences' outputs. Use the `Leapfrog` itself, through
+    /// `RngCore` if needed, to keep drawing from the subsequence.
+    pub fn into_inner(self) -> Random {
+        self.inner
     }
+}
 
-    /// Generates a random number from a Poisson distribution with the specified mean parameter.
-    ///
-    /// # Arguments
-    /// * `mean` - The mean parameter (lambda) of the Poisson distribution.
-    ///
-    /// # Examples
-    /// ```
-    /// use vrd::random::Random;
-    /// let mut rng = Random::new();
-    /// let poisson = rng.poisson(3.0);
-    /// println!("Random number from Poisson distribution with mean 3.0: {}", poisson);
-    /// ```
+impl Iterator for Leapfrog {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        Some(self.rand())
+    }
+}
+
+impl RngCore for Leapfrog {
+    /// Generates the next output of this subsequence.
+    fn next_u32(&mut self) -> u32 {
+        self.rand()
+    }
+
+    /// Combines the next two outputs of this subsequence, high word first, as [`Random::u64`] does.
+    fn next_u64(&mut self) -> u64 {
+        let high = u64::from(self.rand());
+        let low = u64::from(self.rand());
+        (high << 32) | low
+    }
+
+    /// Fills `dest` with the little-endian bytes of successive outputs of this subsequence.
     ///
-    /// # Returns
-    /// An `u64` representing a random number from a Poisson distribution.
-    pub fn poisson(&mut self, mean: f64) -> u64 {
-        let mut k = 0;
-        let mut p = 1.0;
-        let l = (-mean).exp();
-        loop {
-            k += 1;
-            p *= self.f64();
-            if p < l {
-                break;
-            }
+    /// A trailing partial chunk uses the first bytes of one more output, and the rest of it is discarded.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(4) {
+            let bytes = self.rand().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
         }
-        k - 1
     }
 
-    /// Generates a random subslice of the specified length from the given slice.
-    ///
-    /// # Arguments
-    /// * `slice` - The slice from which to generate a random subslice.
-    /// * `length` - The desired length of the random subslice.
-    ///
-    /// # Examples
-    /// ```
-    /// use vrd::random::Random;
-    /// let mut rng = Random::new();
-    /// let slice = &[1, 2, 3, 4, 5];
-    /// let random_subslice = rng.rand_slice(slice, 3);
-    /// println!("Random subslice: {:?}", random_subslice);
-    /// ```
+    /// Attempts to fill `dest` with random data.
     ///
-    /// # Returns
-    /// A `Result<&[T], &str>` containing a randomly generated subslice of the specified length.
-    pub fn rand_slice<'a, T>(
+    /// This method will never fail for this implementation, so it always returns `Ok(())`.
+    fn try_fill_bytes(
         &mut self,
-        slice: &'a [T],
-        length: usize,
-    ) -> Result<&'a [T], &'static str> {
-        if slice.is_empty() {
-            return Err("Input slice is empty");
-        }
-        if length == 0 {
-            return Err("Requested length must be greater than zero");
-        }
-        if length > slice.len() {
-            return Err("Requested length exceeds slice length");
+        dest: &mut [u8],
+    ) -> Result<(), rand::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// A `Random` wrapper whose `Clone` implementation yields an independent stream.
+///
+/// Cloning a plain [`Random`] duplicates its stream exactly, which silently repeats data when a clone was
+/// meant to be a separate source. Cloning a `CloneReseed` instead derives the child with
+/// [`Random::substream`] from the parent's state and a per-parent clone index, so every clone diverges
+/// from its parent and from its siblings. The derivation is deterministic: the same parent state and
+/// clone order always produce the same children.
+///
+/// The wrapper dereferences to the inner `Random`, so every generator method is available on it.
+///
+/// # Examples
+/// ```
+/// use vrd::random::{CloneReseed, Random};
+/// let mut parent = CloneReseed::new(Random::with_seed(42));
+/// let mut child = parent.clone();
+/// assert_ne!(parent.rand(), child.rand());
+/// ```
+#[derive(Debug)]
+pub struct CloneReseed {
+    /// The wrapped generator.
+    inner: Random,
+    /// The number of clones taken from this wrapper, used to give each one a distinct label.
+    clones: AtomicU64,
+}
+
+impl CloneReseed {
+    /// Wraps a generator so that its clones are reseeded.
+    pub fn new(inner: Random) -> Self {
+        CloneReseed {
+            inner,
+            clones: AtomicU64::new(0),
         }
+    }
 
-        let available_start_positions = slice.len() - length + 1;
-        let start = self
-            .random_range(0, available_start_positions as u32)
-            as usize;
-        Ok(&slice[start..start + length])
+    /// Returns the wrapped generator.
+    pub fn into_inner(self) -> Random {
+        self.inner
     }
+}
 
-    /// Randomly samples elements from the given slice without replacement.
-    ///
-    /// # Arguments
-    /// * `slice` - The slice from which to sample elements.
-    /// * `amount` - The number of elements to sample.
-    ///
-    /// # Examples
-    /// ```
-    /// use vrd::random::Random;
-    /// let mut rng = Random::new();
-    /// let slice = &[1, 2, 3, 4, 5];
-    /// let samples = rng.sample(slice, 3);
-    /// println!("Random samples: {:?}", samples);
-    /// ```
-    ///
-    /// # Returns
-    /// A vector containing the randomly sampled elements.
-    pub fn sample<'a, T>(
-        &'a mut self,
-        slice: &'a [T],
-        amount: usize,
-    ) -> Vec<&T> {
-        let mut result = Vec::with_capacity(amount);
-        let mut indices: Vec<usize> = (0..slice.len()).collect();
-        for _ in 0..amount {
-            let index =
-                self.random_range(0, indices.len() as u32) as usize;
-            result.push(&slice[indices[index]]);
-            indices.remove(index);
-        }
-        result
+impl Clone for CloneReseed {
+    fn clone(&self) -> Self {
+        let index = self.clones.fetch_add(1, AtomicOrdering::Relaxed);
+        CloneReseed::new(
+            self.inner.substream(&format!("clone-reseed-{}", index)),
+        )
     }
+}
 
-    /// Randomly samples elements from the given slice with replacement.
-    ///
-    /// # Arguments
-    /// * `slice` - The slice from which to sample elements.
-    /// * `amount` - The number of elements to sample.
-    ///
-    /// # Examples
-    /// ```
-    /// use vrd::random::Random;
-    /// let mut rng = Random::new();
-    /// let slice = &[1, 2, 3, 4, 5];
-    /// let samples = rng.sample_with_replacement(slice, 3);
-    /// println!("Random samples with replacement: {:?}", samples);
-    /// ```
-    ///
-    /// # Returns
-    /// A vector containing the randomly sampled elements with replacement.
-    pub fn sample_with_replacement<'a, T>(
-        &'a mut self,
-        slice: &'a [T],
-        amount: usize,
-    ) -> Vec<&T> {
-        let mut result = Vec::with_capacity(amount);
-        for _ in 0..amount {
-            let index =
-                self.random_range(0, slice.len() as u32) as usize;
-            result.push(&slice[index]);
-        }
-        result
+impl From<Random> for CloneReseed {
+    fn from(inner: Random) -> Self {
+        CloneReseed::new(inner)
     }
+}
 
-    /// Fills the given mutable slice with random values.
-    ///
-    /// # Arguments
-    /// * `slice` - The mutable slice to fill with random values.
-    ///
-    /// # Examples
-    /// ```
-    /// use vrd::random::Random;
-    /// let mut rng = Random::new();
-    /// let mut buffer = [0; 10];
-    /// rng.fill(&mut buffer);
-    /// println!("Filled buffer: {:?}", buffer);
-    /// ```
-    pub fn fill<T>(&mut self, slice: &mut [T])
-    where
-        T: Default
-            + std::ops::RemAssign<u32>
-            + std::ops::BitOrAssign<u32>,
-    {
-        for item in slice.iter_mut() {
-            let random_value = self.rand();
-            *item = T::default();
-            *item %= random_value;
-            *item |= random_value;
-        }
+impl Deref for CloneReseed {
+    type Target = Random;
+
+    fn deref(&self) -> &Random {
+        &self.inner
     }
+}
 
-    /// Shuffles the elements of a mutable slice randomly.
-    ///
-    /// # Arguments
-    /// * `slice` - The mutable slice to shuffle.
-    ///
-    /// # Examples
-    /// ```
-    /// use vrd::random::Random;
-    /// let mut rng = Random::new();
-    /// let mut values = [1, 2, 3, 4, 5];
-    /// rng.shuffle(&mut values);
-    /// println!("Shuffled values: {:?}", values);
-    /// ```
-    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
-        for i in (1..slice.len()).rev() {
-            let j = self.random_range(0, (i + 1) as u32) as usize;
-            slice.swap(i, j);
-        }
+impl DerefMut for CloneReseed {
+    fn deref_mut(&mut self) -> &mut Random {
+        &mut self.inner
+    }
+}
+
+mod sealed {
+    /// Converts an integer to and from an order-preserving `u128` representation.
+    pub trait Sealed: Sized {
+        /// Maps the value to a `u128` so that `a < b` exactly when `a.to_ordered() < b.to_ordered()`.
+        fn to_ordered(self) -> u128;
+        /// Inverts [`Sealed::to_ordered`].
+        fn from_ordered(value: u128) -> Self;
     }
 }
 
+/// A primitive integer type that can be drawn by [`Random::gen_int`].
+///
+/// This trait is sealed: it is implemented for `u8`, `u16`, `u32`, `u64`, `u128`, `usize` and their signed
+/// counterparts, and cannot be implemented outside this crate.
+pub trait RandInt: Copy + PartialOrd + sealed::Sealed {}
+
+/// Implements `RandInt` for unsigned integers, which already sort in the same order as `u128`.
+macro_rules! impl_rand_int_unsigned {
+    ($($ty:ty),*) => {$(
+        // The casts are no-ops for the 128-bit types.
+        #[allow(trivial_numeric_casts)]
+        impl sealed::Sealed for $ty {
+            fn to_ordered(self) -> u128 {
+                self as u128
+            }
+            fn from_ordered(value: u128) -> Self {
+                value as $ty
+            }
+        }
+        impl RandInt for $ty {}
+    )*};
+}
+
+/// Implements `RandInt` for signed integers by sign-extending to `i128` and flipping the sign bit.
+macro_rules! impl_rand_int_signed {
+    ($($ty:ty),*) => {$(
+        // The casts are no-ops for the 128-bit types.
+        #[allow(trivial_numeric_casts)]
+        impl sealed::Sealed for $ty {
+            fn to_ordered(self) -> u128 {
+                (self as i128 as u128) ^ (1 << 127)
+            }
+            fn from_ordered(value: u128) -> Self {
+                ((value ^ (1 << 127)) as i128) as $ty
+            }
+        }
+        impl RandInt for $ty {}
+    )*};
+}
+
+impl_rand_int_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_rand_int_signed!(i8, i16, i32, i64, i128, isize);
+
 impl std::fmt::Display for Random {
     /// Returns a formatted string representation of the `Random` struct.
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -810,6 +5315,10 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
 impl Default for Random {
     /// Returns a default random number generator
     ///
+    /// By default this is the same as [`Random::new`], seeded from OS entropy. With the
+    /// `deterministic-default` feature it is [`Random::unseeded`] instead, so containers and
+    /// `#[derive(Default)]` wrappers that build a `Random` implicitly are reproducible.
+    ///
     /// # Examples
     /// ```
     /// use vrd::random::Random;
@@ -819,7 +5328,14 @@ impl Default for Random {
     /// # Returns
     /// A new instance of `Random` with its internal state initialized for random number generation.
     fn default() -> Self {
-        Self::new()
+        #[cfg(feature = "deterministic-default")]
+        {
+            Self::unseeded()
+        }
+        #[cfg(not(feature = "deterministic-default"))]
+        {
+            Self::new()
+        }
     }
 }
 
@@ -844,7 +5360,16 @@ fn next_u64(&mut self) -> u64 {
     ///
     /// # Arguments
     /// * `dest` - The byte slice to be filled with random data.
+    ///
+    /// With the `simd` feature on x86_64 or aarch64, whole groups of words are tempered together with
+    /// SSE2 or NEON instructions. The output is byte-identical to the scalar path.
     fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let dest = self.drain_pending_bytes(dest);
+        #[cfg(all(
+            feature = "simd",
+            any(target_arch = "x86_64", target_arch = "aarch64")
+        ))]
+        let dest = self.fill_bytes_lanes(dest);
         for chunk in dest.chunks_mut(4) {
             let random_value = self.rand().to_le_bytes();
             chunk.copy_from_slice(&random_value[..chunk.len()]);
@@ -887,7 +5412,7 @@ fn from_seed(seed: Self::Seed) -> Self {
         // Further mix in the seed into the state array
         let mut i = 1;
         let mut j = 0;
-        for _ in 0..624.max(16) {
+        for _ in 0..624 {
             mt[i] = (mt[i]
                 ^ ((mt[i - 1] ^ (mt[i - 1] >> 30))
                     .wrapping_mul(0x6C078965u32)))
@@ -906,6 +5431,18 @@ fn from_seed(seed: Self::Seed) -> Self {
             }
         }
 
-        Random { mt, mti: 624 }
+        Random {
+            mt,
+            mti: 624,
+            params: MersenneTwisterParams::default(),
+            pending_word: 0,
+            pending_offset: 0,
+            bit_word: 0,
+            bit_count: 0,
+            #[cfg(feature = "counters")]
+            draws: 0,
+            #[cfg(feature = "counters")]
+            last: None,
+        }
     }
 }
@@ -0,0 +1,497 @@
+// Copyright © 2023-2024 Random (VRD) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// This file is part of the `Random (VRD)` library, a Rust implementation of the Mersenne Twister RNG.
+// See LICENSE-APACHE.md and LICENSE-MIT.md in the repository root for full license information.
+
+use crate::math;
+use crate::random::{PoissonPtrs, Random, POISSON_PTRS_THRESHOLD};
+use crate::VrdError;
+use serde::{Deserialize, Serialize};
+use std::ops::RangeInclusive;
+
+/// A Bernoulli sampler with a precomputed integer threshold.
+///
+/// Calling [`Random::bool`] repeatedly with the same probability converts and compares floating-point
+/// values on every call. `BernoulliSampler` converts the probability once, so each sample costs a single
+/// `rand()` draw and an integer comparison.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BernoulliSampler {
+    /// Draws strictly below this value produce `true`. It ranges from `0` (never) to `2^32` (always).
+    threshold: u64,
+}
+
+impl BernoulliSampler {
+    /// Creates a new `BernoulliSampler` returning `true` with the given probability.
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The probability of sampling `true`, between 0.0 and 1.0 inclusive.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the new `BernoulliSampler`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `VrdError::GeneralError` if `p` is not between 0.0 and 1.0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vrd::random::Random;
+    /// use vrd::samplers::BernoulliSampler;
+    ///
+    /// let mut rng = Random::new();
+    /// let coin = BernoulliSampler::new(0.25).unwrap();
+    /// let heads = coin.sample(&mut rng);
+    /// println!("Heads: {}", heads);
+    /// ```
+    pub fn new(p: f64) -> Result<Self, VrdError> {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(VrdError::GeneralError(
+                "Probability must be between 0.0 and 1.0".into(),
+            ));
+        }
+        Ok(BernoulliSampler {
+            threshold: (p * 4_294_967_296.0).round() as u64,
+        })
+    }
+
+    /// Draws a single boolean from the sampler.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The generator to draw from. Exactly one `rand()` call is consumed.
+    ///
+    /// # Returns
+    ///
+    /// `true` with the probability the sampler was constructed with.
+    pub fn sample(&self, rng: &mut Random) -> bool {
+        u64::from(rng.rand()) < self.threshold
+    }
+}
+
+/// A mixture of normal distributions for generating multimodal data.
+///
+/// Each component is a `(weight, mu, sigma)` triple. A sample first selects a component with
+/// probability proportional to its weight and then draws from that component with [`Random::normal`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct GaussianMixture {
+    /// The `(weight, mu, sigma)` triple of each component.
+    components: Vec<(f64, f64, f64)>,
+    /// The running total of the weights, used to select a component.
+    cumulative: Vec<f64>,
+}
+
+impl GaussianMixture {
+    /// Creates a new `GaussianMixture` from its components.
+    ///
+    /// # Arguments
+    ///
+    /// * `components` - The `(weight, mu, sigma)` triple of each component. Weights do not need to sum
+    ///   to one; they are normalized by their total.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the new `GaussianMixture`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `VrdError::GeneralError` if a weight is negative or not finite, the weights do not sum
+    /// to a positive value, or a `sigma` is not positive and finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vrd::random::Random;
+    /// use vrd::samplers::GaussianMixture;
+    ///
+    /// let mut rng = Random::new();
+    /// let mixture =
+    ///     GaussianMixture::new(vec![(0.3, -5.0, 1.0), (0.7, 5.0, 1.0)]).unwrap();
+    /// let value = mixture.sample(&mut rng);
+    /// println!("Sample: {}", value);
+    /// ```
+    pub fn new(
+        components: Vec<(f64, f64, f64)>,
+    ) -> Result<Self, VrdError> {
+        let mut cumulative = Vec::with_capacity(components.len());
+        let mut total = 0.0;
+        for &(weight, _, sigma) in &components {
+            if weight < 0.0 || !weight.is_finite() {
+                return Err(VrdError::GeneralError(
+                    "Mixture weights must be non-negative and finite"
+                        .into(),
+                ));
+            }
+            if sigma <= 0.0 || !sigma.is_finite() {
+                return Err(VrdError::GeneralError(
+                    "Mixture sigmas must be positive and finite".into(),
+                ));
+            }
+            total += weight;
+            cumulative.push(total);
+        }
+        if total <= 0.0 || !total.is_finite() {
+            return Err(VrdError::GeneralError(
+                "Mixture weights must sum to a positive value".into(),
+            ));
+        }
+        Ok(GaussianMixture {
+            components,
+            cumulative,
+        })
+    }
+
+    /// Draws a single value from the mixture.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The generator to draw from.
+    ///
+    /// # Returns
+    ///
+    /// A value drawn from the component selected by weight.
+    pub fn sample(&self, rng: &mut Random) -> f64 {
+        let total = self.cumulative[self.cumulative.len() - 1];
+        let target = rng.double() * total;
+        // `double` can return exactly 1.0, so fall back to the last component with a positive weight.
+        let index = self
+            .cumulative
+            .iter()
+            .position(|&c| target < c)
+            .unwrap_or_else(|| {
+                self.components
+                    .iter()
+                    .rposition(|&(weight, _, _)| weight > 0.0)
+                    .unwrap_or(0)
+            });
+        let (_, mu, sigma) = self.components[index];
+        rng.normal(mu, sigma)
+    }
+}
+
+/// An RPG-style d100 roll table mapping ranges of a percentile roll to outcomes.
+///
+/// The ranges must cover `1..=100` exactly once, so each outcome occurs with probability proportional to
+/// the size of its range.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RollTable<T> {
+    /// The `(range, outcome)` entries, sorted by the start of their range.
+    entries: Vec<(RangeInclusive<u32>, T)>,
+}
+
+impl<T> RollTable<T> {
+    /// Creates a new `RollTable` from its entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - The `(range, outcome)` pairs of the table, in any order.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the new `RollTable`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `VrdError::GeneralError` if a range is empty, the ranges overlap, or they leave a gap
+    /// in `1..=100`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vrd::random::Random;
+    /// use vrd::samplers::RollTable;
+    ///
+    /// let mut rng = Random::new();
+    /// let table = RollTable::new(vec![
+    ///     (1..=60, "nothing"),
+    ///     (61..=95, "gold"),
+    ///     (96..=100, "dragon"),
+    /// ])
+    /// .unwrap();
+    /// println!("You find: {}", table.roll(&mut rng));
+    /// ```
+    pub fn new(
+        mut entries: Vec<(RangeInclusive<u32>, T)>,
+    ) -> Result<Self, VrdError> {
+        entries.sort_by_key(|(range, _)| *range.start());
+        let mut next = 1;
+        for (range, _) in &entries {
+            if range.is_empty() {
+                return Err(VrdError::GeneralError(format!(
+                    "Roll table range {:?} is empty",
+                    range
+                )));
+            }
+            if *range.start() < next {
+                return Err(VrdError::GeneralError(format!(
+                    "Roll table range {:?} overlaps a previous range",
+                    range
+                )));
+            }
+            if *range.start() > next {
+                return Err(VrdError::GeneralError(format!(
+                    "Roll table does not cover {}",
+                    next
+                )));
+            }
+            next = range.end().saturating_add(1);
+        }
+        if next != 101 {
+            return Err(VrdError::GeneralError(format!(
+                "Roll table must cover 1..=100, but ends at {}",
+                next - 1
+            )));
+        }
+        Ok(RollTable { entries })
+    }
+
+    /// Rolls a d100 and returns the matching outcome.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The generator to draw from. The roll is `rng.range(1, 100)`.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the outcome whose range contains the roll.
+    pub fn roll(&self, rng: &mut Random) -> &T {
+        let roll = rng.range(1, 100) as u32;
+        let index = self
+            .entries
+            .partition_point(|(range, _)| *range.end() < roll);
+        &self.entries[index].1
+    }
+}
+
+/// A precomputed alias table for sampling a fixed categorical distribution in constant time.
+///
+/// The table is built once with Vose's alias method. Each sample then picks a column and flips a biased coin
+/// between the column and its alias, regardless of the number of categories.
+/// Tables can be serialized with serde and cached instead of being rebuilt.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AliasTable {
+    /// The probability of keeping each column rather than taking its alias.
+    prob: Vec<f64>,
+    /// The category returned when a column's own category is not kept.
+    alias: Vec<usize>,
+}
+
+impl AliasTable {
+    /// Creates a new `AliasTable` from the weights of each category.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - The relative weight of each category. They do not need to sum to one.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the new `AliasTable`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `VrdError::GeneralError` if `weights` is empty or has more than `u32::MAX` entries, a
+    /// weight is negative or not finite, or the weights do not sum to a positive value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vrd::random::Random;
+    /// use vrd::samplers::AliasTable;
+    ///
+    /// let mut rng = Random::new();
+    /// let loaded_die = AliasTable::new(&[1.0, 1.0, 1.0, 1.0, 1.0, 5.0]).unwrap();
+    /// let face = loaded_die.sample(&mut rng) + 1;
+    /// println!("Rolled: {}", face);
+    /// ```
+    pub fn new(weights: &[f64]) -> Result<Self, VrdError> {
+        if weights.is_empty() || weights.len() > u32::MAX as usize {
+            return Err(VrdError::GeneralError(
+                "Alias table needs between 1 and u32::MAX weights"
+                    .into(),
+            ));
+        }
+        if weights.iter().any(|&w| w < 0.0 || !w.is_finite()) {
+            return Err(VrdError::GeneralError(
+                "Alias table weights must be non-negative and finite"
+                    .into(),
+            ));
+        }
+        let total: f64 = weights.iter().sum();
+        if total <= 0.0 || !total.is_finite() {
+            return Err(VrdError::GeneralError(
+                "Alias table weights must sum to a positive value"
+                    .into(),
+            ));
+        }
+
+        let n = weights.len();
+        let mut scaled: Vec<f64> =
+            weights.iter().map(|&w| w * n as f64 / total).collect();
+        let mut prob = vec![1.0; n];
+        let mut alias: Vec<usize> = (0..n).collect();
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) =
+            (0..n).partition(|&i| scaled[i] < 1.0);
+        while let (Some(&less), Some(&more)) =
+            (small.last(), large.last())
+        {
+            let _ = small.pop();
+            let _ = large.pop();
+            prob[less] = scaled[less];
+            alias[less] = more;
+            scaled[more] = (scaled[more] + scaled[less]) - 1.0;
+            if scaled[more] < 1.0 {
+                small.push(more);
+            } else {
+                large.push(more);
+            }
+        }
+        // Whatever remains in either list is 1.0 up to rounding error, so it keeps its own column.
+        Ok(AliasTable { prob, alias })
+    }
+
+    /// Returns the number of categories in the table.
+    pub fn len(&self) -> usize {
+        self.prob.len()
+    }
+
+    /// Returns `true` if the table has no categories, which only happens for a malformed deserialized table.
+    pub fn is_empty(&self) -> bool {
+        self.prob.is_empty()
+    }
+
+    /// Draws a category index from the table.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The generator to draw from. At least three `rand()` calls are consumed per sample: the
+    ///   column is drawn with rejection sampling, as in [`Random::choose`], so that every column is equally
+    ///   likely, and a draw that would bias it is repeated.
+    ///
+    /// # Returns
+    ///
+    /// An index into the weights the table was built from, chosen with probability proportional to its
+    /// weight.
+    pub fn sample(&self, rng: &mut Random) -> usize {
+        let column = rng.index_below(self.prob.len());
+        // `f64_open` never returns 0.0 or 1.0, so a `prob` of 1.0 always keeps the column.
+        if rng.f64_open() < self.prob[column] {
+            column
+        } else {
+            self.alias[column]
+        }
+    }
+}
+
+/// The largest mean for which Knuth's method is safe, since `exp(-mean)` underflows a little above `745`.
+const POISSON_KNUTH_MAX_MEAN: f64 = 700.0;
+
+/// A Poisson sampler with its constants precomputed for a fixed mean.
+///
+/// [`Random::poisson`] recomputes `exp(-mean)` or the PTRS constants and selects the algorithm on every
+/// call. `PoissonSampler` does that once at construction, and the switch from Knuth's method to PTRS can
+/// be moved with [`PoissonSampler::with_threshold`].
+#[derive(Clone, Copy, Debug)]
+pub struct PoissonSampler {
+    /// The threshold `exp(-mean)` used by Knuth's method.
+    knuth_limit: f64,
+    /// The PTRS constants, present when the mean is at or above the switch threshold.
+    ptrs: Option<PoissonPtrs>,
+}
+
+impl PoissonSampler {
+    /// Creates a new `PoissonSampler` using the same algorithm switch as [`Random::poisson`].
+    ///
+    /// Samples are identical to those of `Random::poisson` for the same generator state.
+    ///
+    /// # Arguments
+    ///
+    /// * `mean` - The mean parameter (lambda) of the Poisson distribution.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the new `PoissonSampler`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `VrdError::GeneralError` if `mean` is negative or not finite.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use vrd::random::Random;
+    /// use vrd::samplers::PoissonSampler;
+    ///
+    /// let mut rng = Random::new();
+    /// let arrivals = PoissonSampler::new(4.5).unwrap();
+    /// let count = arrivals.sample(&mut rng);
+    /// println!("Arrivals: {}", count);
+    /// ```
+    pub fn new(mean: f64) -> Result<Self, VrdError> {
+        Self::with_threshold(mean, POISSON_PTRS_THRESHOLD)
+    }
+
+    /// Creates a new `PoissonSampler` that switches from Knuth's method to PTRS at `threshold`.
+    ///
+    /// Knuth's method is exact but consumes `mean + 1` draws on average, while PTRS takes a constant expected
+    /// number of draws and is only valid for means of 10 and above. Raising the threshold keeps the exact
+    /// method for more means at the cost of speed.
+    ///
+    /// # Arguments
+    ///
+    /// * `mean` - The mean parameter (lambda) of the Poisson distribution.
+    /// * `threshold` - The mean at and above which PTRS is used, between 10.0 and 700.0 inclusive.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing the new `PoissonSampler`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `VrdError::GeneralError` if `mean` is negative or not finite, or if `threshold` is outside
+    /// `[10.0, 700.0]`.
+    pub fn with_threshold(
+        mean: f64,
+        threshold: f64,
+    ) -> Result<Self, VrdError> {
+        if !(mean >= 0.0 && mean.is_finite()) {
+            return Err(VrdError::GeneralError(
+                "Poisson mean must be non-negative and finite".into(),
+            ));
+        }
+        if !(POISSON_PTRS_THRESHOLD..=POISSON_KNUTH_MAX_MEAN)
+            .contains(&threshold)
+        {
+            return Err(VrdError::GeneralError(
+                "Poisson threshold must be between 10.0 and 700.0"
+                    .into(),
+            ));
+        }
+        Ok(PoissonSampler {
+            knuth_limit: math::exp(-mean),
+            ptrs: (mean >= threshold).then(|| PoissonPtrs::new(mean)),
+        })
+    }
+
+    /// Returns `true` if the sampler uses Hörmann's transformed rejection method rather than Knuth's.
+    pub fn uses_ptrs(&self) -> bool {
+        self.ptrs.is_some()
+    }
+
+    /// Draws a single count from the sampler.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The generator to draw from.
+    ///
+    /// # Returns
+    ///
+    /// A non-negative count drawn from the Poisson distribution.
+    pub fn sample(&self, rng: &mut Random) -> u64 {
+        match &self.ptrs {
+            Some(ptrs) => ptrs.sample(rng),
+            None => rng.poisson_knuth(self.knuth_limit),
+        }
+    }
+}
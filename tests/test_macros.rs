@@ -3,6 +3,8 @@
 // This file is part of the `Random (VRD)` library, a Rust implementation of the Mersenne Twister RNG.
 // See LICENSE-APACHE.md and LICENSE-MIT.md in the repository root for full license information.
 
+//! Integration tests for the `macros` module.
+
 #[cfg(test)]
 mod tests {
 
@@ -125,7 +127,7 @@ fn test_rand_twist_macro() {
     }
 
     #[test]
-    fn test_rand_range_macro_valid_range() {
+    fn test_random_range_macro_valid_range() {
         let mut rng = Random::new();
         let min = 10;
         let max = 20;
@@ -428,4 +430,111 @@ fn test_rand_bytes() {
         // Check if the result is of the expected length.
         assert_eq!(result.len(), length);
     }
+
+    #[test]
+    fn test_random_range_macro_excludes_max() {
+        let mut rng = Random::with_seed(42);
+        for _ in 0..1000 {
+            assert_eq!(random_range!(rng, 5, 6), 5);
+            let num = random_range!(rng, 0, 4);
+            assert!(num < 4, "random_range! must exclude max.");
+        }
+    }
+
+    #[test]
+    fn test_rand_range_inclusive_macro_includes_max() {
+        let mut rng = Random::with_seed(42);
+        let mut seen = [false; 5];
+        for _ in 0..1000 {
+            let num = rand_range_inclusive!(rng, 0, 4);
+            assert!(num <= 4);
+            seen[num as usize] = true;
+        }
+        assert!(
+            seen.iter().all(|&s| s),
+            "Every value including max should appear."
+        );
+        assert_eq!(rand_range_inclusive!(rng, 7, 7), 7);
+    }
+
+    #[test]
+    fn test_rand_range_inclusive_macro_full_span() {
+        let mut rng = Random::with_seed(42);
+        let mut reference = Random::with_seed(42);
+        let num = rand_range_inclusive!(rng, 0, u32::MAX);
+        assert_eq!(num, reference.rand());
+    }
+
+    #[test]
+    fn test_rand_duration_macro_within_bounds() {
+        let mut rng = Random::with_seed(42);
+        let min = std::time::Duration::from_millis(10);
+        let max = std::time::Duration::from_millis(50);
+        for _ in 0..100 {
+            let delay = rand_duration!(rng, min, max);
+            assert!(delay >= min && delay <= max);
+        }
+    }
+
+    #[test]
+    fn test_rand_uuid_macro_version() {
+        let mut rng = Random::with_seed(42);
+        let mut reference = Random::with_seed(42);
+        let id = rand_uuid!(rng);
+        assert_eq!(id.get_version_num(), 4);
+        assert_eq!(id, reference.uuid());
+    }
+
+    #[test]
+    fn test_rand_subset_macro_matches_method() {
+        let mut rng = Random::with_seed(42);
+        let mut reference = Random::with_seed(42);
+        let values = [1, 2, 3, 4, 5, 6, 7, 8];
+        let subset = rand_subset!(rng, &values, 0.5);
+        assert_eq!(subset, reference.subset(&values, 0.5));
+        assert!(subset.len() <= values.len());
+        assert!(rand_subset!(rng, &values, 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_rand_hex_macro_matches_method() {
+        let mut rng = Random::with_seed(42);
+        let mut reference = Random::with_seed(42);
+        let token = rand_hex!(rng, 12);
+        assert_eq!(token, reference.random_hex(12));
+    }
+
+    /// Test that `rand_choose_weighted!` selects only elements with non-zero weight.
+    #[test]
+    fn test_rand_choose_weighted() {
+        let mut rng = Random::new();
+        let choices = ["A", "B", "C"];
+        let weights = [0, 3, 5];
+        for _ in 0..1000 {
+            let selected =
+                rand_choose_weighted!(rng, &choices, &weights).unwrap();
+            assert_ne!(*selected, "A");
+        }
+    }
+
+    /// Test that `rand_choose_weighted!` reports malformed weights as errors.
+    #[test]
+    fn test_rand_choose_weighted_errors() {
+        let mut rng = Random::new();
+        let choices = ["A", "B", "C"];
+        match rand_choose_weighted!(rng, &choices, &[0, 0, 0]) {
+            Err(VrdError::GeneralError(message)) => assert_eq!(
+                message,
+                "Total weight must be greater than zero"
+            ),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+        assert!(rand_choose_weighted!(rng, &choices, &[1, 2]).is_err());
+        assert!(rand_choose_weighted!(
+            rng,
+            &choices,
+            &[u32::MAX, 1, 0]
+        )
+        .is_err());
+    }
 }
@@ -1,7 +1,10 @@
+//! Integration tests for the `mersenne_twister` module.
+
 #[cfg(test)]
 mod tests {
     use vrd::mersenne_twister::{
-        MersenneTwisterConfig, MersenneTwisterParams,
+        ConfigReport, MersenneTwisterConfig, MersenneTwisterError,
+        MersenneTwisterParams,
     };
 
     #[test]
@@ -263,4 +266,92 @@ fn test_validate_invalid_tempering_mask_c() {
             MersenneTwisterConfig::<624, 397>::validate(&params);
         validation_result.unwrap();
     }
+
+    #[test]
+    fn test_serialize_to_writer_round_trip() {
+        let config = MersenneTwisterConfig::<624, 397>::new().unwrap();
+        let mut buffer = Vec::new();
+        config.serialize_to_writer(&mut buffer).unwrap();
+        let restored =
+            MersenneTwisterConfig::<624, 397>::deserialize_from_reader(
+                buffer.as_slice(),
+            )
+            .unwrap();
+        assert_eq!(restored, config);
+    }
+
+    #[test]
+    fn test_deserialize_from_reader_malformed() {
+        let result =
+            MersenneTwisterConfig::<624, 397>::deserialize_from_reader(
+                &b"{\"params\": {\"matrix_a\": "[..],
+            );
+        assert!(matches!(
+            result,
+            Err(MersenneTwisterError::SerializationError(_))
+        ));
+    }
+
+    #[test]
+    fn test_presets_are_valid() {
+        let presets = [
+            MersenneTwisterParams::MT19937,
+            MersenneTwisterParams::mt19937(),
+        ];
+        for params in presets {
+            MersenneTwisterConfig::<624, 397>::validate(&params)
+                .unwrap();
+            let config =
+                MersenneTwisterConfig::<624, 397>::new_custom(params)
+                    .unwrap();
+            assert_eq!(config.params, MersenneTwisterParams::default());
+        }
+    }
+
+    #[test]
+    fn test_validate_dimensions_mismatch() {
+        let config = MersenneTwisterConfig::<312, 156>::new().unwrap();
+        let error = config.validate_dimensions(624, 397).unwrap_err();
+        assert!(matches!(
+            error,
+            MersenneTwisterError::InvalidConfig(_)
+        ));
+        assert_eq!(
+            error.to_string(),
+            "Invalid configuration: n must be 624 to match the state array, but the config has n = 312"
+        );
+        let config = MersenneTwisterConfig::<624, 400>::new().unwrap();
+        assert_eq!(
+            config.validate_dimensions(624, 397).unwrap_err().to_string(),
+            "Invalid configuration: m must be 397 to match the generator, but the config has m = 400"
+        );
+        MersenneTwisterConfig::<624, 397>::new()
+            .unwrap()
+            .validate_dimensions(624, 397)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_describe_default() {
+        let report =
+            MersenneTwisterConfig::<624, 397>::default().describe();
+        assert!(report.is_default);
+        assert_eq!(report.n, 624);
+        assert_eq!(report.m, 397);
+        assert_eq!(report.params, MersenneTwisterParams::default());
+
+        let json = serde_json::to_string(&report).unwrap();
+        let parsed: ConfigReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, report);
+    }
+
+    #[test]
+    fn test_describe_non_default_dimensions() {
+        let report = MersenneTwisterConfig::<312, 156>::new()
+            .unwrap()
+            .describe();
+        assert!(!report.is_default);
+        assert_eq!(report.n, 312);
+        assert_eq!(report.m, 156);
+    }
 }
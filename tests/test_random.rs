@@ -3,10 +3,24 @@
 // This file is part of the `Random (VRD)` library, a Rust implementation of the Mersenne Twister RNG.
 // See LICENSE-APACHE.md and LICENSE-MIT.md in the repository root for full license information.
 
+//! Integration tests for the `random` module.
+
 #[cfg(test)]
 mod tests {
     use rand::{RngCore, SeedableRng};
-    use vrd::random::Random;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+    use std::time::Duration;
+    use vrd::mersenne_twister::MersenneTwisterParams;
+    use vrd::random::{
+        CloneReseed, Endianness, Leapfrog, QualityReport, Random,
+        StateDiff, StringCharset,
+    };
+    use vrd::MersenneTwisterConfig;
+
+    /// Inverts MT19937 tempering so that `rand` returns `target` from the matching state word.
+    fn untemper(target: u32) -> u32 {
+        Random::untemper(target, &MersenneTwisterParams::default())
+    }
 
     // Initialization tests
     /// Tests the `new` method to ensure that the RNG is initialized correctly.
@@ -159,6 +173,29 @@ fn test_bool() {
         assert!(difference < 500);
     }
 
+    /// Tests that the `bool` fast path for `0.5` is balanced and consumes exactly one draw.
+    #[test]
+    fn test_bool_half_fast_path() {
+        let mut rng = Random::with_seed(42);
+        let mut slow = Random::with_seed(42);
+        assert_eq!(rng.mti(), 624);
+        let _ = rng.bool(0.5);
+        let _ = slow.rand();
+        assert_eq!(rng.mti(), 1);
+        let mut true_count = 0;
+        for _ in 0..10_000 {
+            let value = rng.bool(0.5);
+            let expected =
+                (slow.rand() as f64) < (0.5 * u32::MAX as f64);
+            assert_eq!(value, expected);
+            assert_eq!(rng, slow);
+            if value {
+                true_count += 1;
+            }
+        }
+        assert!((4_500..=5_500).contains(&true_count));
+    }
+
     /// Tests the `char` method to ensure it generates lowercase characters.
     #[test]
     fn test_char() {
@@ -168,6 +205,51 @@ fn test_char() {
         assert!(result.is_ascii_lowercase());
     }
 
+    /// Tests that `char_in_range` skips the surrogate gap and is uniform over valid scalars.
+    #[test]
+    fn test_char_in_range_surrogate_gap() {
+        let mut rng = Random::with_seed(42);
+        let (low, high) = ('\u{D700}', '\u{E0FF}');
+        let mut counts = std::collections::HashMap::new();
+        for _ in 0..51_200 {
+            let c = rng.char_in_range(low, high);
+            assert!((low..=high).contains(&c));
+            assert!(!(0xD800..=0xDFFF).contains(&u32::from(c)));
+            *counts.entry(c).or_insert(0u32) += 1;
+        }
+        // 256 scalars below the gap and 256 above it, about 100 draws each.
+        assert_eq!(counts.len(), 512);
+        assert!(counts.values().all(|&n| (50..160).contains(&n)));
+        let below: u32 = counts
+            .iter()
+            .filter(|(&c, _)| c <= '\u{D7FF}')
+            .map(|(_, &n)| n)
+            .sum();
+        assert!((24_600..26_600).contains(&below), "below: {}", below);
+    }
+
+    /// Tests `char_in_range` on single-value and full ranges.
+    #[test]
+    fn test_char_in_range_bounds() {
+        let mut rng = Random::with_seed(42);
+        assert_eq!(rng.char_in_range('x', 'x'), 'x');
+        for _ in 0..1000 {
+            let c = rng.char_in_range('a', 'z');
+            assert!(c.is_ascii_lowercase());
+            let _ = rng.char_in_range('\0', char::MAX);
+        }
+    }
+
+    /// Tests that `char_in_range` panics when `low` exceeds `high`.
+    #[test]
+    #[should_panic(
+        expected = "low must not exceed high for char_in_range"
+    )]
+    fn test_char_in_range_invalid() {
+        let mut rng = Random::new();
+        let _ = rng.char_in_range('z', 'a');
+    }
+
     // String generation tests
     /// Tests the `string` method to ensure it generates a string of the specified length.
     #[test]
@@ -306,6 +388,60 @@ fn test_shuffle() {
         original_data.iter().for_each(|x| assert!(data.contains(x)));
     }
 
+    /// Tests that `shuffle_indices` keeps parallel arrays aligned and matches `shuffle`.
+    #[test]
+    fn test_shuffle_indices() {
+        let keys: Vec<u32> = (0..50).collect();
+        let values: Vec<u32> = keys.iter().map(|k| k * 10).collect();
+        let mut rng = Random::with_seed(42);
+        let indices = rng.shuffle_indices(keys.len());
+        let mut sorted = indices.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..50).collect::<Vec<_>>());
+
+        let shuffled_keys: Vec<u32> =
+            indices.iter().map(|&i| keys[i]).collect();
+        let shuffled_values: Vec<u32> =
+            indices.iter().map(|&i| values[i]).collect();
+        for (k, v) in shuffled_keys.iter().zip(&shuffled_values) {
+            assert_eq!(k * 10, *v);
+        }
+
+        let mut expected = keys.clone();
+        Random::with_seed(42).shuffle(&mut expected);
+        assert_eq!(shuffled_keys, expected);
+        assert_eq!(Random::with_seed(42).shuffle_indices(50), indices);
+        assert!(rng.shuffle_indices(0).is_empty());
+    }
+
+    /// Tests that `unshuffle` with a matching generator restores the original order.
+    #[test]
+    fn test_unshuffle_restores_order() {
+        for len in [0, 1, 2, 10, 1000] {
+            let original: Vec<usize> = (0..len).collect();
+            let mut data = original.clone();
+            let mut forward = Random::with_seed(42);
+            let mut backward = Random::with_seed(42);
+            forward.shuffle(&mut data);
+            if len >= 10 {
+                assert_ne!(data, original);
+            }
+            backward.unshuffle(&mut data);
+            assert_eq!(data, original);
+            assert_eq!(forward, backward);
+        }
+    }
+
+    /// Tests that `unshuffle` with a different seed does not restore the original order.
+    #[test]
+    fn test_unshuffle_mismatched_seed() {
+        let original: Vec<usize> = (0..100).collect();
+        let mut data = original.clone();
+        Random::with_seed(42).shuffle(&mut data);
+        Random::with_seed(43).unshuffle(&mut data);
+        assert_ne!(data, original);
+    }
+
     /// Tests the `rand_slice` method to ensure it generates a subslice of the specified length.
     #[test]
     fn test_rand_slice() {
@@ -423,6 +559,25 @@ fn test_exponential_zero_rate() {
         assert!(result.is_infinite() && result.is_sign_positive());
     }
 
+    /// Tests that `exponential` stays finite when the generator outputs its extreme values.
+    #[test]
+    fn test_exponential_extreme_draws() {
+        for target in [0, u32::MAX] {
+            let mut rng = Random::with_seed(42);
+            rng.mt[0] = untemper(target);
+            rng.set_mti(0);
+            assert_eq!(rng.clone().rand(), target);
+            let result = rng.exponential(1.5);
+            assert!(result.is_finite() && result >= 0.0);
+        }
+
+        let mut rng = Random::with_seed(42);
+        for _ in 0..2_000_000 {
+            let result = rng.exponential(1.5);
+            assert!(result.is_finite() && result >= 0.0);
+        }
+    }
+
     /// Tests the `poisson` method to ensure it generates numbers from a Poisson distribution.
     #[test]
     fn test_poisson() {
@@ -487,6 +642,38 @@ fn test_fill_bytes() {
         assert!(buffer.iter().any(|&x| x != 0));
     }
 
+    /// Tests that `fill_bytes` matches sequential `rand` calls across lengths, offsets and twists.
+    #[test]
+    fn test_fill_bytes_matches_scalar() {
+        for offset in [0, 1, 7, 620] {
+            for len in [0, 3, 32, 33, 100, 4096, 5003] {
+                let mut rng = Random::with_seed(42);
+                for _ in 0..offset {
+                    let _ = rng.rand();
+                }
+                let mut reference = rng.clone();
+                let mut buffer = vec![0u8; len];
+                rng.fill_bytes(&mut buffer);
+                let mut expected = Vec::with_capacity(len + 4);
+                while expected.len() < len {
+                    expected.extend_from_slice(
+                        &reference.rand().to_le_bytes(),
+                    );
+                }
+                expected.truncate(len);
+                assert_eq!(buffer, expected);
+                assert_eq!(rng, reference);
+            }
+        }
+
+        let mut never_seeded = Random::with_seed(1);
+        never_seeded.set_mti(625);
+        let mut reference = Random::unseeded();
+        let mut buffer = [0u8; 64];
+        never_seeded.fill_bytes(&mut buffer);
+        assert_eq!(&buffer[..4], &reference.rand().to_le_bytes());
+    }
+
     /// Tests the `try_fill_bytes` method from `RngCore` to ensure it fills a byte slice and returns `Ok(())`.
     #[test]
     fn test_try_fill_bytes() {
@@ -712,4 +899,3171 @@ fn test_from_seed_with_extreme_values() {
             );
         }
     }
+
+    // State diff tests
+    /// Tests that `state_diff` returns `None` for identically seeded generators.
+    #[test]
+    fn test_state_diff_identical() {
+        let mut rng1 = Random::new();
+        let mut rng2 = Random::new();
+        rng1.seed(42);
+        rng2.seed(42);
+        assert_eq!(rng1.state_diff(&rng2), None);
+    }
+
+    /// Tests that `state_diff` reports the first differing index and both values.
+    #[test]
+    fn test_state_diff_detects_flipped_word() {
+        let mut rng1 = Random::new();
+        rng1.seed(42);
+        let mut rng2 = rng1.clone();
+        rng2.mt[311] ^= 0x0000_0100;
+        rng2.mt[500] ^= 1;
+
+        assert_eq!(
+            rng1.state_diff(&rng2),
+            Some(StateDiff::Word {
+                index: 311,
+                self_value: rng1.mt[311],
+                other_value: rng2.mt[311],
+            })
+        );
+        assert_ne!(rng1.mt[311], rng2.mt[311]);
+    }
+
+    /// Tests that `state_diff` reports a difference in `mti` alone.
+    #[test]
+    fn test_state_diff_reports_mti_only() {
+        let mut rng1 = Random::new();
+        rng1.seed(42);
+        let mut rng2 = rng1.clone();
+        rng2.set_mti(100);
+        assert_eq!(
+            rng1.state_diff(&rng2),
+            Some(StateDiff::IndexOnly {
+                self_mti: 624,
+                other_mti: 100,
+            })
+        );
+        assert_ne!(rng1, rng2);
+    }
+
+    // Discrete distribution tests
+    /// Tests that `sample_discrete` produces frequencies matching the PMF.
+    #[test]
+    fn test_sample_discrete_frequencies() {
+        let mut rng = Random::new();
+        rng.seed(42);
+        let pmf = [0.1, 0.2, 0.3, 0.4];
+        let samples = 100_000;
+        let mut counts = [0usize; 4];
+        for _ in 0..samples {
+            counts[rng.sample_discrete(&pmf)] += 1;
+        }
+        for (count, p) in counts.iter().zip(pmf.iter()) {
+            let frequency = *count as f64 / samples as f64;
+            assert!((frequency - p).abs() < 0.01);
+        }
+    }
+
+    /// Tests that `sample_discrete` never selects zero-probability outcomes.
+    #[test]
+    fn test_sample_discrete_zero_probability() {
+        let mut rng = Random::new();
+        rng.seed(7);
+        let pmf = [0.0, 1.0, 0.0];
+        for _ in 0..1000 {
+            assert_eq!(rng.sample_discrete(&pmf), 1);
+        }
+    }
+
+    /// Tests that `sample_discrete` panics on a non-normalized PMF.
+    #[test]
+    #[should_panic(
+        expected = "pmf probabilities must sum to 1.0 for sample_discrete"
+    )]
+    fn test_sample_discrete_not_normalized() {
+        let mut rng = Random::new();
+        rng.sample_discrete(&[0.5, 0.6]);
+    }
+
+    /// Tests that `sample_discrete` panics on a negative probability.
+    #[test]
+    #[should_panic(
+        expected = "pmf probabilities must be non-negative for sample_discrete"
+    )]
+    fn test_sample_discrete_negative() {
+        let mut rng = Random::new();
+        rng.sample_discrete(&[1.5, -0.5]);
+    }
+
+    /// Tests that `fill` populates `u8` buffers reproducibly from the high bits of `rand`.
+    #[test]
+    fn test_fill_u8() {
+        let mut rng = Random::new();
+        rng.seed(42);
+        let mut buffer = [0u8; 32];
+        rng.fill(&mut buffer);
+        assert!(buffer.iter().any(|&x| x != 0));
+
+        rng.seed(42);
+        let expected: Vec<u8> =
+            (0..32).map(|_| (rng.rand() >> 24) as u8).collect();
+        assert_eq!(buffer.to_vec(), expected);
+    }
+
+    /// Tests that `fill` populates `u16` buffers reproducibly from the high bits of `rand`.
+    #[test]
+    fn test_fill_u16() {
+        let mut rng = Random::new();
+        rng.seed(42);
+        let mut buffer = vec![0u16; 32];
+        rng.fill(&mut buffer[..]);
+        assert!(buffer.iter().any(|&x| x != 0));
+
+        rng.seed(42);
+        let expected: Vec<u16> =
+            (0..32).map(|_| (rng.rand() >> 16) as u16).collect();
+        assert_eq!(buffer, expected);
+    }
+
+    /// Tests that `fill` populates `u32` buffers with consecutive `rand` outputs.
+    #[test]
+    fn test_fill_u32_reproducible() {
+        let mut rng = Random::new();
+        rng.seed(42);
+        let mut buffer = [0u32; 16];
+        rng.fill(&mut buffer);
+
+        rng.seed(42);
+        let expected: Vec<u32> = (0..16).map(|_| rng.rand()).collect();
+        assert_eq!(buffer.to_vec(), expected);
+    }
+
+    /// Tests that `fill` populates `u64` buffers with consecutive `u64` outputs.
+    #[test]
+    fn test_fill_u64() {
+        let mut rng = Random::new();
+        rng.seed(42);
+        let mut buffer = [0u64; 8];
+        rng.fill(&mut buffer);
+        assert!(buffer.iter().any(|&x| x > u64::from(u32::MAX)));
+
+        rng.seed(42);
+        let expected: Vec<u64> = (0..8).map(|_| rng.u64()).collect();
+        assert_eq!(buffer.to_vec(), expected);
+    }
+
+    /// Tests that `poisson_batch` matches individual `poisson` calls for small means.
+    #[test]
+    fn test_poisson_batch_matches_poisson() {
+        let mut rng1 = Random::new();
+        let mut rng2 = Random::new();
+        rng1.seed(42);
+        rng2.seed(42);
+        let batch = rng1.poisson_batch(3.0, 1000);
+        let individual: Vec<u64> =
+            (0..1000).map(|_| rng2.poisson(3.0)).collect();
+        assert_eq!(batch, individual);
+    }
+
+    /// Tests that `poisson_batch` produces samples whose mean and variance approach `mean`.
+    #[test]
+    fn test_poisson_batch_moments() {
+        let mut rng = Random::new();
+        rng.seed(42);
+        for &mean in &[3.0, 50.0, 1000.0] {
+            let samples = rng.poisson_batch(mean, 50_000);
+            let n = samples.len() as f64;
+            let sample_mean =
+                samples.iter().map(|&x| x as f64).sum::<f64>() / n;
+            let variance = samples
+                .iter()
+                .map(|&x| (x as f64 - sample_mean).powi(2))
+                .sum::<f64>()
+                / n;
+            assert!((sample_mean - mean).abs() < mean * 0.02 + 0.05);
+            assert!((variance - mean).abs() < mean * 0.05 + 0.1);
+        }
+    }
+
+    /// Tests that `poisson` terminates and stays near the mean where `exp(-mean)` underflows.
+    #[test]
+    fn test_poisson_large_mean() {
+        let mut rng = Random::new();
+        rng.seed(42);
+        let mean = 10_000.0;
+        let average =
+            (0..1000).map(|_| rng.poisson(mean) as f64).sum::<f64>()
+                / 1000.0;
+        assert!((average - mean).abs() < 20.0);
+    }
+
+    /// Tests that `unseeded` matches the reference MT19937 output for the default seed `5489`.
+    #[test]
+    fn test_unseeded_reference_output() {
+        let mut rng = Random::unseeded();
+        assert_eq!(rng.rand(), 3499211612);
+        assert_eq!(rng.rand(), 581869302);
+        assert_eq!(rng.rand(), 3890346734);
+    }
+
+    /// Tests that `unseeded` generators are always identical.
+    #[test]
+    fn test_unseeded_deterministic() {
+        assert_eq!(Random::unseeded(), Random::unseeded());
+    }
+
+    /// Tests `map_to_range` at the boundaries of the input and output ranges.
+    #[test]
+    fn test_map_to_range_boundaries() {
+        assert_eq!(Random::map_to_range(0, 10, 20), 10);
+        assert_eq!(Random::map_to_range(u32::MAX, 10, 20), 19);
+        assert_eq!(Random::map_to_range(u32::MAX, 5, 6), 5);
+        assert_eq!(
+            Random::map_to_range(u32::MAX, 0, u32::MAX),
+            u32::MAX - 1
+        );
+        assert_eq!(Random::map_to_range(12345, 0, u32::MAX), 12344);
+    }
+
+    /// Tests that `map_to_range` spreads a uniform sweep of inputs evenly across the range.
+    #[test]
+    fn test_map_to_range_uniform_sweep() {
+        let mut counts = [0u32; 7];
+        let step = 4099u32;
+        let mut value = 0u32;
+        let mut total = 0u32;
+        while let Some(next) = value.checked_add(step) {
+            let bucket = Random::map_to_range(value, 0, 7);
+            counts[bucket as usize] += 1;
+            total += 1;
+            value = next;
+        }
+        let expected = f64::from(total) / 7.0;
+        for &count in &counts {
+            assert!((f64::from(count) - expected).abs() <= 1.0);
+        }
+    }
+
+    /// Tests that `map_to_range` panics when the range is empty.
+    #[test]
+    #[should_panic(
+        expected = "max must be greater than min for map_to_range"
+    )]
+    fn test_map_to_range_invalid() {
+        Random::map_to_range(1, 5, 5);
+    }
+
+    // Parameter configuration tests
+    /// Tests that `with_params_and_seed` produces a reproducible sequence for valid parameters.
+    #[test]
+    fn test_with_params_and_seed_reproducible() {
+        let params = MersenneTwisterParams::default();
+        let mut rng1 =
+            Random::with_params_and_seed(params, 42).unwrap();
+        let mut rng2 =
+            Random::with_params_and_seed(params, 42).unwrap();
+        let mut reference = Random::with_seed(42);
+        for _ in 0..1000 {
+            let value = rng1.rand();
+            assert_eq!(value, rng2.rand());
+            assert_eq!(value, reference.rand());
+        }
+    }
+
+    /// Tests that custom parameters are stored and change the output sequence.
+    #[test]
+    fn test_with_params_and_seed_custom_params() {
+        let params = MersenneTwisterParams {
+            matrix_a: 0x8000_0001,
+            ..MersenneTwisterParams::default()
+        };
+        let mut custom =
+            Random::with_params_and_seed(params, 42).unwrap();
+        let mut default = Random::with_seed(42);
+        assert_eq!(custom.params, params);
+        let custom_values: Vec<u32> =
+            (0..10).map(|_| custom.rand()).collect();
+        let default_values: Vec<u32> =
+            (0..10).map(|_| default.rand()).collect();
+        assert_ne!(custom_values, default_values);
+    }
+
+    /// Tests that `with_params_and_seed` rejects invalid parameters.
+    #[test]
+    fn test_with_params_and_seed_invalid() {
+        let params = MersenneTwisterParams {
+            matrix_a: 0x7fff_ffff,
+            ..MersenneTwisterParams::default()
+        };
+        let result = Random::with_params_and_seed(params, 42);
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("matrix_a must have its highest bit set"));
+    }
+
+    /// Tests that `random_unit_vector_n` returns unit vectors of the requested length.
+    #[test]
+    fn test_random_unit_vector_n() {
+        let mut rng = Random::with_seed(42);
+        for dim in 1..=10 {
+            let vector = rng.random_unit_vector_n(dim);
+            assert_eq!(vector.len(), dim);
+            let norm = vector.iter().map(|x| x * x).sum::<f64>().sqrt();
+            assert!((norm - 1.0).abs() < 1e-9);
+        }
+    }
+
+    /// Tests that `random_unit_vector_n` is reproducible under a fixed seed.
+    #[test]
+    fn test_random_unit_vector_n_reproducible() {
+        let mut rng1 = Random::with_seed(7);
+        let mut rng2 = Random::with_seed(7);
+        assert_eq!(
+            rng1.random_unit_vector_n(16),
+            rng2.random_unit_vector_n(16)
+        );
+    }
+
+    /// Tests that `random_unit_vector_n` panics for a zero dimension.
+    #[test]
+    #[should_panic(
+        expected = "dim must be at least 1 for random_unit_vector_n"
+    )]
+    fn test_random_unit_vector_n_zero_dim() {
+        let mut rng = Random::new();
+        rng.random_unit_vector_n(0);
+    }
+
+    // Derived seeding tests
+    /// Tests that `seed_from_bytes` matches the reference `init_by_array` output.
+    #[test]
+    fn test_seed_from_bytes_reference() {
+        // The reference MT19937 key {0x123, 0x234, 0x345, 0x456} as little-endian bytes.
+        let key: Vec<u8> = [0x123u32, 0x234, 0x345, 0x456]
+            .iter()
+            .flat_map(|word| word.to_le_bytes())
+            .collect();
+        let mut rng = Random::new();
+        rng.seed_from_bytes(&key);
+        assert_eq!(rng.rand(), 1067595299);
+        assert_eq!(rng.rand(), 955945823);
+        assert_eq!(rng.rand(), 477289528);
+    }
+
+    /// Tests that identical source/target pairs reseed identically via `reseed_from`.
+    #[test]
+    fn test_reseed_from_reproducible() {
+        let mut source1 = Random::with_seed(42);
+        let mut source2 = Random::with_seed(42);
+        let mut target1 = Random::with_seed(1);
+        let mut target2 = Random::with_seed(1);
+
+        target1.reseed_from(&mut source1);
+        target2.reseed_from(&mut source2);
+
+        assert_eq!(target1, target2);
+        assert_eq!(source1, source2);
+        for _ in 0..100 {
+            assert_eq!(target1.rand(), target2.rand());
+        }
+    }
+
+    /// Tests that `reseed_from` changes the target's output and advances the source.
+    #[test]
+    fn test_reseed_from_changes_output() {
+        let mut source = Random::with_seed(42);
+        let mut target = Random::with_seed(1);
+
+        let mut untouched = target.clone();
+        target.reseed_from(&mut source);
+        let reseeded: Vec<u32> =
+            (0..10).map(|_| target.rand()).collect();
+        let original: Vec<u32> =
+            (0..10).map(|_| untouched.rand()).collect();
+        assert_ne!(reseeded, original);
+
+        let mut expected_source = Random::with_seed(42);
+        for _ in 0..8 {
+            expected_source.rand();
+        }
+        assert_eq!(source.rand(), expected_source.rand());
+    }
+
+    // Backoff tests
+    /// Tests that `jittered_backoff` never exceeds the cap or the exponential bound.
+    #[test]
+    fn test_jittered_backoff_bounds() {
+        let mut rng = Random::with_seed(42);
+        let base = Duration::from_millis(100);
+        let cap = Duration::from_secs(5);
+        for attempt in 0..100 {
+            let bound = if attempt < 6 {
+                base * 2u32.pow(attempt)
+            } else {
+                cap
+            };
+            for _ in 0..100 {
+                let delay = rng.jittered_backoff(base, attempt, cap);
+                assert!(delay <= cap);
+                assert!(delay <= bound);
+            }
+        }
+    }
+
+    /// Tests that `jittered_backoff` delays grow with the attempt number until capped.
+    #[test]
+    fn test_jittered_backoff_grows() {
+        let mut rng = Random::with_seed(42);
+        let base = Duration::from_millis(10);
+        let cap = Duration::from_millis(1000);
+        let mean = |rng: &mut Random, attempt: u32| {
+            (0..2000)
+                .map(|_| {
+                    rng.jittered_backoff(base, attempt, cap)
+                        .as_secs_f64()
+                })
+                .sum::<f64>()
+                / 2000.0
+        };
+        let means: Vec<f64> =
+            (0..10).map(|a| mean(&mut rng, a)).collect();
+        for pair in means[..7].windows(2) {
+            assert!(pair[1] > pair[0]);
+        }
+        // Attempts 7 and beyond are capped at one second, so the mean settles near half of it.
+        assert!((means[9] - 0.5).abs() < 0.05);
+    }
+
+    /// Tests that `jittered_backoff` is reproducible and saturates on huge attempts.
+    #[test]
+    fn test_jittered_backoff_reproducible_and_saturating() {
+        let base = Duration::from_secs(1);
+        let cap = Duration::from_secs(60);
+        let mut rng1 = Random::with_seed(7);
+        let mut rng2 = Random::with_seed(7);
+        for attempt in [0, 5, 31, 32, 1000, u32::MAX] {
+            let delay = rng1.jittered_backoff(base, attempt, cap);
+            assert_eq!(
+                delay,
+                rng2.jittered_backoff(base, attempt, cap)
+            );
+            assert!(delay <= cap);
+        }
+    }
+
+    /// Tests that `with_seed` generators report the default configuration.
+    #[test]
+    fn test_config_default() {
+        let rng = Random::with_seed(42);
+        assert!(rng.uses_default_config());
+        assert_eq!(
+            rng.config(),
+            MersenneTwisterConfig::<624, 397>::default()
+        );
+    }
+
+    /// Tests that `with_params_and_seed` generators report their custom parameters.
+    #[test]
+    fn test_config_custom() {
+        let params = MersenneTwisterParams {
+            matrix_a: 0x8000_0001,
+            ..MersenneTwisterParams::default()
+        };
+        let rng = Random::with_params_and_seed(params, 42).unwrap();
+        assert!(!rng.uses_default_config());
+        assert_eq!(rng.config().params, params);
+    }
+
+    /// Tests that mutating through `choose_mut` updates the underlying slice.
+    #[test]
+    fn test_choose_mut_updates_slice() {
+        let mut rng = Random::with_seed(42);
+        let mut values = [0u32; 8];
+        for _ in 0..100 {
+            *rng.choose_mut(&mut values).unwrap() += 1;
+        }
+        assert_eq!(values.iter().sum::<u32>(), 100);
+    }
+
+    /// Tests that `choose_mut` selects the same position as `choose` under a fixed seed.
+    #[test]
+    fn test_choose_mut_reproducible() {
+        let mut rng1 = Random::with_seed(42);
+        let mut rng2 = Random::with_seed(42);
+        let data = [10, 20, 30, 40, 50, 60, 70];
+        let mut copy = data;
+        for _ in 0..50 {
+            let chosen = *rng1.choose(&data).unwrap();
+            assert_eq!(*rng2.choose_mut(&mut copy).unwrap(), chosen);
+        }
+    }
+
+    /// Tests that `choose_mut` returns `None` for an empty slice.
+    #[test]
+    fn test_choose_mut_empty_slice() {
+        let mut rng = Random::new();
+        let empty: &mut [i32] = &mut [];
+        assert!(rng.choose_mut(empty).is_none());
+    }
+
+    /// Tests that `choose_two_distinct` returns elements at different indices.
+    #[test]
+    fn test_choose_two_distinct_indices() {
+        let mut rng = Random::with_seed(42);
+        let data: Vec<usize> = (0..5).collect();
+        for _ in 0..1000 {
+            let (first, second) =
+                rng.choose_two_distinct(&data).unwrap();
+            assert_ne!(first, second);
+        }
+        let pair = [1, 2];
+        let (first, second) = rng.choose_two_distinct(&pair).unwrap();
+        assert_eq!(first + second, 3);
+    }
+
+    /// Tests that `choose_two_distinct` is reproducible and handles short slices.
+    #[test]
+    fn test_choose_two_distinct_reproducible() {
+        let mut rng1 = Random::with_seed(42);
+        let mut rng2 = Random::with_seed(42);
+        let data = [10, 20, 30, 40, 50, 60, 70];
+        for _ in 0..50 {
+            assert_eq!(
+                rng1.choose_two_distinct(&data),
+                rng2.choose_two_distinct(&data)
+            );
+        }
+        assert!(rng1.choose_two_distinct(&[1]).is_none());
+        assert!(rng1.choose_two_distinct::<i32>(&[]).is_none());
+    }
+
+    /// Tests that `choose_two_distinct` rejects biased draws for both indices.
+    #[test]
+    fn test_choose_two_distinct_rejection() {
+        let data = [10, 20, 30, 40, 50, 60, 70];
+        // 2^32 mod 7 and 2^32 mod 6 are both 4, so `u32::MAX` is rejected for either index.
+        let mut rng = Random::new();
+        rng.mt[0] = untemper(u32::MAX);
+        rng.mt[1] = untemper(10);
+        rng.mt[2] = untemper(u32::MAX);
+        rng.mt[3] = untemper(8);
+        rng.mti = 0;
+        assert_eq!(rng.choose_two_distinct(&data), Some((&40, &30)));
+        assert_eq!(rng.mti, 4);
+    }
+
+    // Password generation tests
+    /// Tests that `generate_password` includes every required class and respects the length.
+    #[test]
+    fn test_generate_password_classes() {
+        let mut rng = Random::with_seed(42);
+        for _ in 0..200 {
+            let password =
+                rng.generate_password(4, true, true, true, true);
+            assert_eq!(password.len(), 4);
+            assert!(password.chars().any(|c| c.is_ascii_lowercase()));
+            assert!(password.chars().any(|c| c.is_ascii_uppercase()));
+            assert!(password.chars().any(|c| c.is_ascii_digit()));
+            assert!(password.chars().any(|c| c.is_ascii_punctuation()));
+        }
+    }
+
+    /// Tests that `generate_password` only uses characters from the enabled classes.
+    #[test]
+    fn test_generate_password_enabled_only() {
+        let mut rng = Random::with_seed(42);
+        let password =
+            rng.generate_password(64, false, false, true, false);
+        assert_eq!(password.len(), 64);
+        assert!(password.chars().all(|c| c.is_ascii_digit()));
+    }
+
+    /// Tests that `generate_password` is reproducible under a fixed seed.
+    #[test]
+    fn test_generate_password_reproducible() {
+        let mut rng1 = Random::with_seed(7);
+        let mut rng2 = Random::with_seed(7);
+        assert_eq!(
+            rng1.generate_password(20, true, true, true, true),
+            rng2.generate_password(20, true, true, true, true)
+        );
+    }
+
+    /// Tests that `generate_password` rejects draws that would bias a character class.
+    #[test]
+    fn test_generate_password_rejection() {
+        // 2^32 mod 10 is 6, so `u32::MAX` is rejected for the digit class.
+        let mut rng = Random::new();
+        rng.mt[0] = untemper(u32::MAX);
+        rng.mt[1] = untemper(13);
+        rng.mti = 0;
+        assert_eq!(
+            rng.generate_password(1, false, false, true, false),
+            "3"
+        );
+        assert_eq!(rng.mti, 2);
+    }
+
+    /// Tests that `generate_password` panics when the length cannot satisfy the requirements.
+    #[test]
+    #[should_panic(
+        expected = "length must be at least the number of required classes for generate_password"
+    )]
+    fn test_generate_password_too_short() {
+        let mut rng = Random::new();
+        rng.generate_password(3, true, true, true, true);
+    }
+
+    /// Tests that identically seeded pairs produce the same interleaved output.
+    #[test]
+    fn test_interleaved_bytes_reproducible() {
+        let mut a1 = Random::with_seed(1);
+        let mut b1 = Random::with_seed(2);
+        let mut a2 = Random::with_seed(1);
+        let mut b2 = Random::with_seed(2);
+        let first = a1.interleaved_bytes(&mut b1, 33);
+        assert_eq!(first.len(), 33);
+        assert_eq!(first, a2.interleaved_bytes(&mut b2, 33));
+    }
+
+    /// Tests that the interleaved output alternates between the generators' byte streams.
+    #[test]
+    fn test_interleaved_bytes_alternates() {
+        let mut a = Random::with_seed(1);
+        let mut b = Random::with_seed(2);
+        let combined = a.interleaved_bytes(&mut b, 20);
+        let from_a = Random::with_seed(1).bytes(10);
+        let from_b = Random::with_seed(2).bytes(10);
+        for i in 0..10 {
+            assert_eq!(combined[2 * i], from_a[i]);
+            assert_eq!(combined[2 * i + 1], from_b[i]);
+        }
+    }
+
+    /// Tests that swapping the generators changes the interleaved output.
+    #[test]
+    fn test_interleaved_bytes_order_matters() {
+        let mut a1 = Random::with_seed(1);
+        let mut b1 = Random::with_seed(2);
+        let mut a2 = Random::with_seed(1);
+        let mut b2 = Random::with_seed(2);
+        assert_ne!(
+            a1.interleaved_bytes(&mut b1, 32),
+            b2.interleaved_bytes(&mut a2, 32)
+        );
+    }
+
+    // Byte order tests
+    /// Tests the byte layout of both orders against a known seed.
+    #[test]
+    fn test_fill_bytes_with_endian_layout() {
+        // The first two outputs for seed 42 are 0x5fe1dc66 and 0xcbea3db3.
+        let mut rng = Random::with_seed(42);
+        let mut little = [0u8; 8];
+        rng.fill_bytes_with_endian(&mut little, Endianness::Little);
+        assert_eq!(
+            little,
+            [0x66, 0xdc, 0xe1, 0x5f, 0xb3, 0x3d, 0xea, 0xcb]
+        );
+
+        let mut rng = Random::with_seed(42);
+        let mut big = [0u8; 8];
+        rng.fill_bytes_with_endian(&mut big, Endianness::Big);
+        assert_eq!(
+            big,
+            [0x5f, 0xe1, 0xdc, 0x66, 0xcb, 0xea, 0x3d, 0xb3]
+        );
+    }
+
+    /// Tests that the little-endian order matches `RngCore::fill_bytes`, including partial chunks.
+    #[test]
+    fn test_fill_bytes_with_endian_matches_fill_bytes() {
+        let mut rng1 = Random::with_seed(7);
+        let mut rng2 = Random::with_seed(7);
+        let mut a = [0u8; 11];
+        let mut b = [0u8; 11];
+        rng1.fill_bytes_with_endian(&mut a, Endianness::Little);
+        rng2.fill_bytes(&mut b);
+        assert_eq!(a, b);
+        assert_eq!(rng1, rng2);
+    }
+
+    // Bit quality tests
+    /// Tests that a healthy generator passes the monobit test.
+    #[test]
+    fn test_monobit_test_healthy() {
+        let mut rng = Random::with_seed(42);
+        let p_value = rng.monobit_test(100_000);
+        assert!(p_value >= 0.01, "p-value {} too small", p_value);
+        assert!(p_value <= 1.0);
+    }
+
+    /// Tests that a degenerate all-zero stream fails the monobit test.
+    #[test]
+    fn test_monobit_test_skewed() {
+        let mut rng = Random::with_seed(42);
+        rng.mt = [0; 624];
+        rng.set_mti(0);
+        assert_eq!(rng.clone().rand(), 0);
+        let p_value = rng.monobit_test(1000);
+        assert!(p_value < 0.01);
+    }
+
+    /// Tests that the monobit test consumes whole words and handles partial words.
+    #[test]
+    fn test_monobit_test_partial_word() {
+        let mut rng = Random::with_seed(42);
+        let mut reference = Random::with_seed(42);
+        let p_value = rng.monobit_test(40);
+        assert!((0.0..=1.0).contains(&p_value));
+        reference.rand();
+        reference.rand();
+        assert_eq!(rng, reference);
+    }
+
+    /// Tests that `draw_many` matches the same number of sequential `rand` calls.
+    #[test]
+    fn test_draw_many() {
+        let mut rng = Random::with_seed(42);
+        let mut reference = Random::with_seed(42);
+        let words = rng.draw_many::<8>();
+        let expected: Vec<u32> =
+            (0..8).map(|_| reference.rand()).collect();
+        assert_eq!(words.to_vec(), expected);
+        assert_eq!(rng, reference);
+        let empty: [u32; 0] = rng.draw_many();
+        assert!(empty.is_empty());
+        assert_eq!(rng, reference);
+    }
+
+    /// Tests that `seed_sequence` produces distinct, reproducible generators.
+    #[test]
+    fn test_seed_sequence() {
+        let mut family = Random::seed_sequence(&[1, 2, 3], 4);
+        let mut again = Random::seed_sequence(&[1, 2, 3], 4);
+        assert_eq!(family.len(), 4);
+        let outputs: Vec<Vec<u32>> = family
+            .iter_mut()
+            .map(|rng| (0..16).map(|_| rng.rand()).collect())
+            .collect();
+        for i in 0..outputs.len() {
+            for j in i + 1..outputs.len() {
+                assert_ne!(outputs[i], outputs[j]);
+            }
+        }
+        for (rng, expected) in again.iter_mut().zip(&outputs) {
+            let drawn: Vec<u32> = (0..16).map(|_| rng.rand()).collect();
+            assert_eq!(&drawn, expected);
+        }
+        let mut other = Random::seed_sequence(&[1, 2, 4], 1);
+        let drawn: Vec<u32> =
+            (0..16).map(|_| other[0].rand()).collect();
+        assert_ne!(drawn, outputs[0]);
+        assert!(Random::seed_sequence(&[], 0).is_empty());
+
+        // Matches `std::mt19937` seeded from `std::seed_seq{1, 2, 3}`.
+        let mut reference = Random::seed_sequence(&[1, 2, 3], 1);
+        assert_eq!(reference[0].rand(), 1710881851);
+        assert_eq!(reference[0].rand(), 703781052);
+    }
+
+    /// Tests that substreams are reproducible per label and distinct across labels.
+    #[test]
+    fn test_substream() {
+        let master = Random::with_seed(42);
+        let snapshot = master.clone();
+        let mut arrivals = master.substream("arrivals");
+        let mut arrivals_again = master.substream("arrivals");
+        let mut service = master.substream("service-times");
+        assert_eq!(master, snapshot);
+        let a: Vec<u32> = (0..16).map(|_| arrivals.rand()).collect();
+        let b: Vec<u32> =
+            (0..16).map(|_| arrivals_again.rand()).collect();
+        let c: Vec<u32> = (0..16).map(|_| service.rand()).collect();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+
+        let mut other_master =
+            Random::with_seed(43).substream("arrivals");
+        let d: Vec<u32> =
+            (0..16).map(|_| other_master.rand()).collect();
+        assert_ne!(a, d);
+    }
+
+    /// Tests that generators with the same continuation share a canonical form.
+    #[test]
+    fn test_canonical() {
+        let due = Random::with_seed(42);
+        let mut twisted = due.clone();
+        twisted.twist();
+        assert_ne!(due, twisted);
+        assert_eq!(due.canonical(), twisted.canonical());
+        assert_eq!(due.canonical().mti(), 0);
+
+        let mut a = due.canonical();
+        let mut b = due.clone();
+        for _ in 0..1000 {
+            assert_eq!(a.rand(), b.rand());
+        }
+
+        let mut never_seeded = Random::with_seed(1);
+        never_seeded.set_mti(625);
+        assert_eq!(
+            never_seeded.canonical(),
+            Random::unseeded().canonical()
+        );
+
+        let mut midway = Random::with_seed(42);
+        let _ = midway.rand();
+        assert_eq!(midway.canonical(), midway);
+        assert_ne!(midway.canonical(), twisted.canonical());
+    }
+
+    // Compact serialization tests
+    /// Tests that the compact JSON form round-trips the generator state.
+    #[test]
+    fn test_json_compact_round_trip() {
+        let mut rng = Random::with_seed(42);
+        for _ in 0..10 {
+            let _ = rng.rand();
+        }
+        let json = rng.to_json_compact().unwrap();
+        assert!(json.starts_with("{\"state\":\""));
+        assert!(json.ends_with(",\"mti\":10}"));
+        let mut restored = Random::from_json_compact(&json).unwrap();
+        assert_eq!(rng.mt, restored.mt);
+        assert_eq!(rng.mti(), restored.mti());
+        assert_eq!(rng.rand(), restored.rand());
+    }
+
+    /// Tests that the compact JSON form is much smaller than the derived serde form.
+    #[test]
+    fn test_json_compact_size() {
+        let rng = Random::with_seed(42);
+        let compact = rng.to_json_compact().unwrap();
+        let derived = serde_json::to_string(&rng).unwrap();
+        assert!(compact.len() * 2 < derived.len());
+    }
+
+    /// Tests that malformed compact JSON is rejected.
+    #[test]
+    fn test_json_compact_invalid() {
+        assert!(Random::from_json_compact("not json").is_err());
+        assert!(Random::from_json_compact(
+            "{\"state\":\"!!!\",\"mti\":0}"
+        )
+        .is_err());
+        assert!(Random::from_json_compact(
+            "{\"state\":\"AAAA\",\"mti\":0}"
+        )
+        .is_err());
+        let json = Random::with_seed(42)
+            .to_json_compact()
+            .unwrap()
+            .replace("\"mti\":624", "\"mti\":626");
+        assert!(Random::from_json_compact(&json).is_err());
+    }
+
+    /// Tests that `advance_to_twist_boundary` aligns to `mti == 0` and preserves the continuation.
+    #[test]
+    fn test_advance_to_twist_boundary() {
+        for drawn in [0, 1, 100, 623, 624, 625] {
+            let mut rng = Random::with_seed(42);
+            for _ in 0..drawn {
+                let _ = rng.rand();
+            }
+            let mut reference = rng.clone();
+            let mti_before = rng.mti();
+            let skipped = rng.advance_to_twist_boundary();
+            assert_eq!(rng.mti(), 0);
+            assert_eq!(skipped, (624 - mti_before) % 624);
+            for _ in 0..skipped {
+                let _ = reference.rand();
+            }
+            for _ in 0..1000 {
+                assert_eq!(rng.rand(), reference.rand());
+            }
+        }
+
+        let mut aligned = Random::with_seed(42);
+        aligned.twist();
+        let snapshot = aligned.clone();
+        assert_eq!(aligned.advance_to_twist_boundary(), 0);
+        assert_eq!(aligned, snapshot);
+    }
+
+    /// Tests that `duration` stays within its inclusive bounds.
+    #[test]
+    fn test_duration_bounds() {
+        let mut rng = Random::with_seed(42);
+        let min = Duration::from_millis(10);
+        let max = Duration::from_millis(50);
+        for _ in 0..1000 {
+            let delay = rng.duration(min, max);
+            assert!(delay >= min && delay <= max);
+        }
+        assert_eq!(rng.duration(max, max), max);
+        let full = rng.duration(Duration::ZERO, Duration::MAX);
+        assert!(full.as_nanos() <= u128::from(u64::MAX));
+    }
+
+    /// Tests that `duration` panics when `min` exceeds `max`.
+    #[test]
+    #[should_panic(expected = "min must not exceed max for duration")]
+    fn test_duration_invalid_range() {
+        let mut rng = Random::new();
+        let _ = rng
+            .duration(Duration::from_secs(2), Duration::from_secs(1));
+    }
+
+    /// Tests that `uuid` produces reproducible version 4 UUIDs.
+    #[test]
+    fn test_uuid() {
+        let mut rng1 = Random::with_seed(42);
+        let mut rng2 = Random::with_seed(42);
+        let id = rng1.uuid();
+        assert_eq!(id, rng2.uuid());
+        assert_eq!(id.get_version_num(), 4);
+        assert_eq!(id.get_variant(), uuid::Variant::RFC4122);
+        assert_ne!(rng1.uuid(), id);
+    }
+
+    /// Tests that `subset` keeps order and honours its probability.
+    #[test]
+    fn test_subset() {
+        let mut rng = Random::with_seed(42);
+        let values: Vec<u32> = (0..1000).collect();
+        assert!(rng.subset(&values, 0.0).is_empty());
+        assert_eq!(rng.subset(&values, 1.0).len(), values.len());
+        let subset = rng.subset(&values, 0.3);
+        assert!((200..400).contains(&subset.len()));
+        assert!(subset.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    /// Tests that `subset` panics for a probability outside `[0, 1]`.
+    #[test]
+    #[should_panic(
+        expected = "p must be between 0.0 and 1.0 for subset"
+    )]
+    fn test_subset_invalid_probability() {
+        let mut rng = Random::new();
+        let _ = rng.subset(&[1, 2, 3], 1.5);
+    }
+
+    // Weighted reservoir tests
+    /// Tests that `weighted_reservoir` returns `min(k, count)` items.
+    #[test]
+    fn test_weighted_reservoir_size() {
+        let mut rng = Random::with_seed(42);
+        let items = || (0..10).map(|i| (i, 1.0));
+        assert_eq!(rng.weighted_reservoir(items(), 3).len(), 3);
+        assert_eq!(rng.weighted_reservoir(items(), 10).len(), 10);
+        assert_eq!(rng.weighted_reservoir(items(), 25).len(), 10);
+        assert!(rng.weighted_reservoir(items(), 0).is_empty());
+
+        let mut all = rng.weighted_reservoir(items(), 10);
+        all.sort_unstable();
+        assert_eq!(all, (0..10).collect::<Vec<_>>());
+    }
+
+    /// Tests that heavier items appear in the reservoir more often.
+    #[test]
+    fn test_weighted_reservoir_bias() {
+        let mut rng = Random::with_seed(42);
+        let mut counts = [0u32; 20];
+        for _ in 0..2000 {
+            let items =
+                (0..20).map(|i| (i, if i == 17 { 20.0 } else { 1.0 }));
+            let picked = rng.weighted_reservoir(items, 2);
+            assert_eq!(picked.len(), 2);
+            assert_ne!(picked[0], picked[1]);
+            for i in picked {
+                counts[i] += 1;
+            }
+        }
+        let heavy = counts[17];
+        let light_max = counts
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| i != 17)
+            .map(|(_, &c)| c)
+            .max()
+            .unwrap();
+        assert!(heavy > 3 * light_max, "{:?}", counts);
+        assert!(counts.iter().all(|&c| c > 0), "{:?}", counts);
+    }
+
+    /// Tests that `weighted_reservoir` rejects non-positive weights.
+    #[test]
+    #[should_panic(
+        expected = "weights must be positive and finite for weighted_reservoir"
+    )]
+    fn test_weighted_reservoir_invalid_weight() {
+        let mut rng = Random::new();
+        let _ = rng.weighted_reservoir(vec![(1, 1.0), (2, 0.0)], 1);
+    }
+
+    /// Tests that `next_u32_checked` rejects an uninitialized generator.
+    #[test]
+    fn test_next_u32_checked() {
+        let mut uninitialized = Random::with_seed(42);
+        uninitialized.mt = [0; 624];
+        uninitialized.set_mti(625);
+        let snapshot = uninitialized.clone();
+        assert!(uninitialized.next_u32_checked().is_err());
+        assert_eq!(uninitialized, snapshot);
+
+        let mut seeded = Random::with_seed(42);
+        let mut reference = Random::with_seed(42);
+        assert_eq!(
+            seeded.next_u32_checked().unwrap(),
+            reference.rand()
+        );
+        assert_eq!(seeded, reference);
+
+        let mut unseeded = Random::unseeded();
+        assert_eq!(unseeded.next_u32_checked().unwrap(), 3499211612);
+    }
+
+    /// Tests that every source and their order affect `seed_from_sources`.
+    #[test]
+    fn test_seed_from_sources() {
+        fn draws(sources: &[u32]) -> Vec<u32> {
+            let mut rng = Random::new();
+            rng.seed_from_sources(sources);
+            (0..8).map(|_| rng.rand()).collect()
+        }
+
+        let base = [1234, 1_700_000_000, 7];
+        let reference = draws(&base);
+        assert_eq!(reference, draws(&base));
+        for i in 0..base.len() {
+            let mut changed = base;
+            changed[i] ^= 1;
+            assert_ne!(draws(&changed), reference);
+        }
+        assert_ne!(draws(&[7, 1_700_000_000, 1234]), reference);
+        assert_eq!(draws(&[]), draws(&[0]));
+
+        let mut rng = Random::new();
+        rng.seed_from_sources(&[0x123, 0x234, 0x345, 0x456]);
+        assert_eq!(rng.rand(), 1067595299);
+        assert_eq!(rng.rand(), 955945823);
+    }
+
+    /// Tests that `from_label` is stable per label and distinct across labels.
+    #[test]
+    fn test_from_label() {
+        let mut rng1 = Random::from_label("experiment-3");
+        let mut rng2 = Random::from_label("experiment-3");
+        let first: Vec<u32> = (0..8).map(|_| rng1.rand()).collect();
+        let second: Vec<u32> = (0..8).map(|_| rng2.rand()).collect();
+        assert_eq!(first, second);
+        // Pinned so that any change to the hash is caught.
+        assert_eq!(first[0], 2326478469);
+
+        let mut other = Random::from_label("experiment-4");
+        let third: Vec<u32> = (0..8).map(|_| other.rand()).collect();
+        assert_ne!(first, third);
+
+        // The label hash is 64-bit FNV-1a; "a" hashes to 0xaf63dc4c8601ec8c.
+        let mut expected = Random::new();
+        expected.seed_from_bytes(&0xaf63dc4c8601ec8cu64.to_le_bytes());
+        assert_eq!(Random::from_label("a").rand(), expected.rand());
+    }
+
+    /// Tests that `draw_count` tracks the raw words consumed by each method.
+    #[cfg(feature = "counters")]
+    #[test]
+    fn test_draw_count() {
+        let mut rng = Random::with_seed(42);
+        assert_eq!(rng.draw_count(), 0);
+        let _ = rng.rand();
+        assert_eq!(rng.draw_count(), 1);
+        let _ = rng.int(1, 10);
+        let _ = rng.bool(0.5);
+        assert_eq!(rng.draw_count(), 3);
+        let _ = rng.u64();
+        assert_eq!(rng.draw_count(), 5);
+        let mut bytes = [0u8; 64];
+        rng.fill_bytes(&mut bytes);
+        assert_eq!(rng.draw_count(), 21);
+        for _ in 0..1000 {
+            let _ = rng.rand();
+        }
+        assert_eq!(rng.draw_count(), 1021);
+        rng.seed(7);
+        assert_eq!(rng.draw_count(), 1021);
+    }
+
+    /// Tests that `poisson_f64` matches `poisson` converted to `f64`.
+    #[test]
+    fn test_poisson_f64() {
+        for &mean in &[3.0, 50.0] {
+            let mut rng1 = Random::with_seed(42);
+            let mut rng2 = Random::with_seed(42);
+            for _ in 0..100 {
+                let value = rng1.poisson_f64(mean);
+                assert_eq!(value, rng2.poisson(mean) as f64);
+                assert_eq!(value.fract(), 0.0);
+            }
+            assert_eq!(rng1, rng2);
+        }
+    }
+
+    /// Tests that `clamped_normal` stays in bounds and piles mass onto the boundaries.
+    #[test]
+    fn test_clamped_normal() {
+        let mut rng = Random::with_seed(42);
+        let mut at_low = 0;
+        let mut at_high = 0;
+        for _ in 0..10_000 {
+            let value = rng.clamped_normal(0.0, 1.0, -1.0, 1.0);
+            assert!((-1.0..=1.0).contains(&value));
+            if value == -1.0 {
+                at_low += 1;
+            } else if value == 1.0 {
+                at_high += 1;
+            }
+        }
+        // About 15.9% of a standard normal lies beyond each bound.
+        assert!((1_300..1_900).contains(&at_low), "low: {}", at_low);
+        assert!((1_300..1_900).contains(&at_high), "high: {}", at_high);
+
+        let mut rng1 = Random::with_seed(7);
+        let mut rng2 = Random::with_seed(7);
+        let clamped = rng1.clamped_normal(0.0, 1.0, -10.0, 10.0);
+        assert_eq!(clamped, rng2.normal(0.0, 1.0));
+    }
+
+    /// Tests that `clamped_normal` panics for inverted bounds.
+    #[test]
+    #[should_panic(
+        expected = "low must not exceed high for clamped_normal"
+    )]
+    fn test_clamped_normal_invalid_bounds() {
+        let mut rng = Random::new();
+        let _ = rng.clamped_normal(0.0, 1.0, 1.0, -1.0);
+    }
+
+    /// Tests that canonically equal generators share a sequence hash.
+    #[test]
+    fn test_sequence_hash() {
+        let due = Random::with_seed(42);
+        let mut twisted = due.clone();
+        twisted.twist();
+        assert_ne!(due, twisted);
+        assert_eq!(due.sequence_hash(), twisted.sequence_hash());
+        assert_eq!(due.canonical(), twisted.canonical());
+
+        let mut never_seeded = Random::with_seed(1);
+        never_seeded.set_mti(625);
+        assert_eq!(
+            never_seeded.sequence_hash(),
+            Random::unseeded().sequence_hash()
+        );
+
+        let mut advanced = twisted.clone();
+        let _ = advanced.rand();
+        assert_ne!(advanced.sequence_hash(), twisted.sequence_hash());
+        assert_ne!(
+            Random::with_seed(43).sequence_hash(),
+            due.sequence_hash()
+        );
+    }
+
+    /// Tests that `f64_open` never returns either endpoint and has a mean near 0.5.
+    #[test]
+    fn test_f64_open() {
+        let mut rng = Random::with_seed(42);
+        let mut sum = 0.0;
+        for _ in 0..200_000 {
+            let u = rng.f64_open();
+            assert!(u > 0.0 && u < 1.0);
+            sum += u;
+        }
+        let mean = sum / 200_000.0;
+        assert!((mean - 0.5).abs() < 0.005, "mean {}", mean);
+
+        // The extreme draws still map strictly inside the interval.
+        let mut lowest = Random::with_seed(42);
+        lowest.mt[0] = untemper(0);
+        lowest.mt[1] = untemper(0);
+        lowest.set_mti(0);
+        let smallest = lowest.f64_open();
+        assert!(smallest > 0.0 && smallest < 1e-15);
+
+        let mut highest = Random::with_seed(42);
+        highest.mt[0] = untemper(u32::MAX);
+        highest.mt[1] = untemper(u32::MAX);
+        highest.set_mti(0);
+        let largest = highest.f64_open();
+        assert!(largest < 1.0 && largest > 1.0 - 1e-15);
+    }
+
+    /// Tests that `fill_uninit` writes the same bytes as `fill_bytes`.
+    #[cfg(feature = "uninit")]
+    #[test]
+    fn test_fill_uninit_matches_fill_bytes() {
+        use std::mem::MaybeUninit;
+        for len in [0, 3, 4, 33, 1000] {
+            let mut rng = Random::with_seed(42);
+            let mut reference = Random::with_seed(42);
+            let mut buf = vec![MaybeUninit::<u8>::uninit(); len];
+            let filled = rng.fill_uninit(&mut buf).to_vec();
+            let mut expected = vec![0u8; len];
+            reference.fill_bytes(&mut expected);
+            assert_eq!(filled, expected);
+            assert_eq!(rng.mti(), reference.mti());
+        }
+    }
+
+    /// Tests that a healthy generator produces a report with every metric in range.
+    #[test]
+    fn test_quality_report_healthy() {
+        let mut rng = Random::with_seed(42);
+        let report = rng.quality_report(100_000);
+        assert_eq!(report.samples, 100_000);
+        // 255 degrees of freedom: mean 255, standard deviation about 22.6.
+        assert!(
+            (160.0..360.0).contains(&report.chi_square),
+            "{:?}",
+            report
+        );
+        assert!(report.monobit_p_value >= 0.01, "{:?}", report);
+        assert!(
+            report.byte_entropy > 7.99 && report.byte_entropy <= 8.0
+        );
+        assert!(report.min < 1 << 20);
+        assert!(report.max > u32::MAX - (1 << 20));
+
+        let json = serde_json::to_string(&report).unwrap();
+        let restored: QualityReport =
+            serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, report);
+    }
+
+    /// Tests that a degenerate stream produces a failing report.
+    #[test]
+    fn test_quality_report_degenerate() {
+        let mut rng = Random::with_seed(42);
+        rng.mt = [0; 624];
+        rng.set_mti(0);
+        let report = rng.quality_report(500);
+        assert_eq!(report.min, 0);
+        assert_eq!(report.max, 0);
+        assert_eq!(report.byte_entropy, 0.0);
+        assert!(report.monobit_p_value < 0.01);
+        assert!(report.chi_square > 100_000.0);
+    }
+
+    /// Tests that `discard_bytes` followed by `fill_bytes` matches slicing the full stream.
+    #[test]
+    fn test_discard_bytes_matches_stream() {
+        let mut whole = Random::with_seed(42);
+        let mut stream = vec![0u8; 4096];
+        whole.fill_bytes(&mut stream);
+        for skip in [0, 1, 4, 5, 7, 8, 2499] {
+            let mut resumed = Random::with_seed(42);
+            resumed.discard_bytes(skip);
+            let mut rest = vec![0u8; 1000];
+            resumed.fill_bytes(&mut rest);
+            assert_eq!(
+                &stream[skip..skip + 1000],
+                &rest[..],
+                "{}",
+                skip
+            );
+        }
+    }
+
+    /// Tests that repeated small discards accumulate within a pending word.
+    #[test]
+    fn test_discard_bytes_incremental() {
+        let mut whole = Random::with_seed(42);
+        let mut stream = [0u8; 64];
+        whole.fill_bytes(&mut stream);
+
+        let mut resumed = Random::with_seed(42);
+        resumed.discard_bytes(1);
+        resumed.discard_bytes(2);
+        resumed.discard_bytes(3);
+        let mut first = [0u8; 1];
+        resumed.fill_bytes(&mut first);
+        assert_eq!(first[0], stream[6]);
+        let mut next = [0u8; 9];
+        resumed.fill_bytes(&mut next);
+        assert_eq!(&next, &stream[7..16]);
+
+        let mut reseeded = Random::with_seed(42);
+        reseeded.discard_bytes(3);
+        reseeded.seed(42);
+        let mut fresh = [0u8; 8];
+        reseeded.fill_bytes(&mut fresh);
+        assert_eq!(&fresh, &stream[..8]);
+    }
+
+    /// Tests that `fill_bytes_with_endian` honours bytes left pending by `discard_bytes`.
+    #[test]
+    fn test_discard_bytes_fill_bytes_with_endian() {
+        for len in [1, 2, 3, 4, 11] {
+            let mut rng = Random::with_seed(42);
+            let mut reference = Random::with_seed(42);
+            rng.discard_bytes(5);
+            reference.discard_bytes(5);
+            let mut little = vec![0u8; len];
+            let mut expected = vec![0u8; len];
+            rng.fill_bytes_with_endian(&mut little, Endianness::Little);
+            reference.fill_bytes(&mut expected);
+            assert_eq!(little, expected, "{}", len);
+            assert_eq!(rng, reference);
+        }
+
+        // The big-endian order drops the pending bytes and starts at the next whole output.
+        let mut rng = Random::with_seed(42);
+        rng.discard_bytes(5);
+        let mut big = [0u8; 4];
+        rng.fill_bytes_with_endian(&mut big, Endianness::Big);
+        let mut reference = Random::with_seed(42);
+        let _ = reference.rand();
+        let _ = reference.rand();
+        assert_eq!(big, reference.rand().to_be_bytes());
+        let mut next = [0u8; 4];
+        rng.fill_bytes(&mut next);
+        assert_eq!(next, reference.rand().to_le_bytes());
+    }
+
+    /// Tests that `fill_uninit` honours bytes left pending by `discard_bytes`.
+    #[cfg(feature = "uninit")]
+    #[test]
+    fn test_discard_bytes_fill_uninit() {
+        use std::mem::MaybeUninit;
+        for len in [0, 1, 2, 3, 4, 11] {
+            let mut rng = Random::with_seed(42);
+            let mut reference = Random::with_seed(42);
+            rng.discard_bytes(5);
+            reference.discard_bytes(5);
+            let mut buf = vec![MaybeUninit::<u8>::uninit(); len];
+            let filled = rng.fill_uninit(&mut buf).to_vec();
+            let mut expected = vec![0u8; len];
+            reference.fill_bytes(&mut expected);
+            assert_eq!(filled, expected, "{}", len);
+            assert_eq!(rng, reference);
+        }
+    }
+
+    /// Tests that the compact JSON form resumes pending bytes and buffered bits.
+    #[test]
+    fn test_json_compact_resumes_buffers() {
+        let mut rng = Random::with_seed(42);
+        rng.discard_bytes(5);
+        let _ = rng.bits_be(3);
+        let json = rng.to_json_compact().unwrap();
+        assert!(
+            json.contains("\"pending\":[")
+                && json.contains("\"bits\":[")
+        );
+        let mut restored = Random::from_json_compact(&json).unwrap();
+        assert_eq!(restored.bits_be(7), rng.bits_be(7));
+        let mut expected = [0u8; 7];
+        let mut resumed = [0u8; 7];
+        rng.fill_bytes(&mut expected);
+        restored.fill_bytes(&mut resumed);
+        assert_eq!(resumed, expected);
+
+        let json =
+            json.replace("\"pending\":[", "\"pending\":[0,4],\"x\":[");
+        assert!(Random::from_json_compact(&json).is_err());
+    }
+
+    /// Tests that `poisson_process` yields increasing times with about `rate * duration` events.
+    #[test]
+    fn test_poisson_process() {
+        let mut rng = Random::with_seed(42);
+        let (rate, duration) = (3.0, 20.0);
+        let mut total = 0;
+        for _ in 0..1000 {
+            let events = rng.poisson_process(rate, duration);
+            assert!(events.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(events
+                .iter()
+                .all(|&t| (0.0..duration).contains(&t)));
+            total += events.len();
+        }
+        let mean = total as f64 / 1000.0;
+        assert!((mean - rate * duration).abs() < 1.0, "mean {}", mean);
+    }
+
+    /// Tests that `poisson_process` panics for a non-positive rate.
+    #[test]
+    #[should_panic(
+        expected = "rate must be positive and finite for poisson_process"
+    )]
+    fn test_poisson_process_invalid_rate() {
+        let mut rng = Random::new();
+        let _ = rng.poisson_process(0.0, 1.0);
+    }
+
+    /// Tests that `poisson_process` panics for a non-positive duration.
+    #[test]
+    #[should_panic(
+        expected = "duration must be positive and finite for poisson_process"
+    )]
+    fn test_poisson_process_invalid_duration() {
+        let mut rng = Random::new();
+        let _ = rng.poisson_process(1.0, -1.0);
+    }
+
+    // State save and restore tests
+    /// Tests that `seed_swap` followed by `set_state` restores the prior continuation.
+    #[test]
+    fn test_seed_swap_restores_continuation() {
+        let mut rng = Random::with_seed(42);
+        for _ in 0..100 {
+            let _ = rng.rand();
+        }
+        let mut reference = rng.clone();
+        let (mt, mti) = rng.seed_swap(7);
+        let mut seven = Random::with_seed(7);
+        for _ in 0..700 {
+            assert_eq!(rng.rand(), seven.rand());
+        }
+        rng.set_state(mt, mti);
+        assert_eq!(rng.state(), reference.state());
+        for _ in 0..1000 {
+            assert_eq!(rng.rand(), reference.rand());
+        }
+    }
+
+    /// Tests that `state` and `set_state` round-trip and validate the index.
+    #[test]
+    fn test_state_round_trip() {
+        let mut rng = Random::with_seed(42);
+        let _ = rng.rand();
+        let (mt, mti) = rng.state();
+        assert_eq!(mti, 1);
+        let mut other = Random::with_seed(1);
+        other.set_state(mt, mti);
+        assert_eq!(other.state(), rng.state());
+        assert_eq!(other.rand(), rng.rand());
+    }
+
+    /// Tests that `set_state` rejects an out-of-range index.
+    #[test]
+    #[should_panic(expected = "mti must not exceed 625 for set_state")]
+    fn test_set_state_invalid_index() {
+        let mut rng = Random::new();
+        rng.set_state([0; 624], 626);
+    }
+
+    // Network address tests
+    /// Tests that IPv4 addresses fall inside the requested block and are reproducible.
+    #[test]
+    fn test_random_ipv4_in() {
+        let mut rng = Random::with_seed(42);
+        let base = Ipv4Addr::new(192, 168, 77, 200);
+        for _ in 0..1000 {
+            let address = rng.random_ipv4_in(base, 20);
+            assert_eq!(u32::from(address) >> 12, u32::from(base) >> 12);
+        }
+        assert_eq!(rng.random_ipv4_in(base, 32), base);
+        let _ = rng.random_ipv4_in(base, 0);
+
+        let mut rng1 = Random::with_seed(42);
+        let mut rng2 = Random::with_seed(42);
+        assert_eq!(rng1.random_ipv4(), rng2.random_ipv4());
+        assert_eq!(rng1.random_ipv4(), Ipv4Addr::from(rng2.rand()));
+    }
+
+    /// Tests that IPv6 addresses fall inside the requested block and are reproducible.
+    #[test]
+    fn test_random_ipv6_in() {
+        let mut rng = Random::with_seed(42);
+        let base: Ipv6Addr = "2001:db8:abcd::1".parse().unwrap();
+        for _ in 0..1000 {
+            let address = rng.random_ipv6_in(base, 48);
+            assert_eq!(address.segments()[..3], base.segments()[..3]);
+        }
+        assert_eq!(rng.random_ipv6_in(base, 128), base);
+        let _ = rng.random_ipv6_in(base, 0);
+
+        let mut rng1 = Random::with_seed(42);
+        let mut rng2 = Random::with_seed(42);
+        assert_eq!(rng1.random_ipv6(), rng2.random_ipv6());
+        assert_ne!(rng1.random_ipv6(), rng1.random_ipv6());
+    }
+
+    /// Tests that an IPv4 prefix longer than 32 bits is rejected.
+    #[test]
+    #[should_panic(
+        expected = "prefix_len must not exceed 32 for random_ipv4_in"
+    )]
+    fn test_random_ipv4_in_invalid_prefix() {
+        let mut rng = Random::new();
+        let _ = rng.random_ipv4_in(Ipv4Addr::LOCALHOST, 33);
+    }
+
+    /// Tests that an IPv6 prefix longer than 128 bits is rejected.
+    #[test]
+    #[should_panic(
+        expected = "prefix_len must not exceed 128 for random_ipv6_in"
+    )]
+    fn test_random_ipv6_in_invalid_prefix() {
+        let mut rng = Random::new();
+        let _ = rng.random_ipv6_in(Ipv6Addr::LOCALHOST, 129);
+    }
+
+    // CDF sampling tests
+
+    /// Tests that `choose_from_cdf` picks the same index as `sample_discrete` for the same distribution.
+    #[test]
+    fn test_choose_from_cdf_matches_sample_discrete() {
+        let pmf = [0.1, 0.0, 0.25, 0.4, 0.25];
+        let mut running = 0.0;
+        let cdf: Vec<f64> = pmf
+            .iter()
+            .map(|&p| {
+                running += p;
+                running
+            })
+            .collect();
+        let mut a = Random::with_seed(2024);
+        let mut b = Random::with_seed(2024);
+        for _ in 0..10_000 {
+            assert_eq!(
+                a.choose_from_cdf(&cdf),
+                b.sample_discrete(&pmf)
+            );
+        }
+    }
+
+    /// Tests that `choose_from_cdf` resolves draws landing exactly on bucket boundaries.
+    #[test]
+    fn test_choose_from_cdf_boundaries() {
+        let mut rng = Random::new();
+        // A draw of exactly 0.0 skips the leading zero-probability bucket.
+        rng.mt[0] = untemper(0);
+        rng.mt[1] = untemper(0);
+        rng.mti = 0;
+        assert_eq!(rng.choose_from_cdf(&[0.0, 0.5, 1.0]), 1);
+        // The largest draw, just below 1.0, falls into the first bucket reaching the final value.
+        rng.mt[2] = untemper(u32::MAX);
+        rng.mt[3] = untemper(u32::MAX);
+        assert_eq!(
+            rng.choose_from_cdf(&[0.5, 0.9999999, 0.9999999]),
+            1
+        );
+    }
+
+    /// Tests that `choose_from_cdf` panics on an unsorted CDF.
+    #[test]
+    #[should_panic(
+        expected = "cdf must be non-negative and sorted for choose_from_cdf"
+    )]
+    fn test_choose_from_cdf_unsorted() {
+        let mut rng = Random::new();
+        let _ = rng.choose_from_cdf(&[0.6, 0.4, 1.0]);
+    }
+
+    /// Tests that `choose_from_cdf` panics on a CDF that does not end at 1.0.
+    #[test]
+    #[should_panic(
+        expected = "cdf must end at 1.0 for choose_from_cdf"
+    )]
+    fn test_choose_from_cdf_not_normalized() {
+        let mut rng = Random::new();
+        let _ = rng.choose_from_cdf(&[0.2, 0.9]);
+    }
+
+    // Tempering tests
+
+    /// Tests that `untemper` inverts `temper` across edge values and a large set of inputs.
+    #[test]
+    fn test_temper_untemper_round_trip() {
+        let params = MersenneTwisterParams::default();
+        let edges = [0, 1, 0x8000_0000, 0x7fff_ffff, u32::MAX];
+        let mut rng = Random::with_seed(99);
+        let inputs: Vec<u32> = edges
+            .iter()
+            .copied()
+            .chain((0..100_000).map(|_| rng.rand()))
+            .collect();
+        for x in inputs {
+            let tempered = Random::temper(x, &params);
+            assert_eq!(Random::untemper(tempered, &params), x);
+            assert_eq!(
+                Random::temper(Random::untemper(x, &params), &params),
+                x
+            );
+        }
+    }
+
+    /// Tests that `rand` returns the tempered state word it consumes.
+    #[test]
+    fn test_rand_uses_temper() {
+        let mut rng = Random::with_seed(42);
+        let first = rng.rand();
+        assert_eq!(first, 1608637542);
+        assert_eq!(Random::temper(rng.mt[0], &rng.params), first);
+        assert_eq!(Random::untemper(first, &rng.params), rng.mt[0]);
+    }
+
+    // Byte array tests
+
+    /// Tests that `byte_array` matches `fill_bytes` under an identical seed.
+    #[test]
+    fn test_byte_array_matches_fill_bytes() {
+        let mut a = Random::with_seed(5);
+        let mut b = Random::with_seed(5);
+        let array = a.byte_array::<16>();
+        let mut buffer = [0u8; 16];
+        b.fill_bytes(&mut buffer);
+        assert_eq!(array, buffer);
+        assert_eq!(a.next_u32(), b.next_u32());
+    }
+
+    /// Tests that `byte_array` uses all four bytes of each draw, including a partial final draw.
+    #[test]
+    fn test_byte_array_uses_whole_words() {
+        let mut a = Random::with_seed(5);
+        let mut b = Random::with_seed(5);
+        let array = a.byte_array::<6>();
+        let first = b.rand().to_le_bytes();
+        let second = b.rand().to_le_bytes();
+        assert_eq!(array[..4], first);
+        assert_eq!(array[4..], second[..2]);
+        assert_eq!(a.rand(), b.rand());
+        assert_eq!(Random::new().byte_array::<0>(), [0u8; 0]);
+    }
+
+    // Charset string tests
+
+    /// Tests that each `StringCharset` variant only produces characters from its alphabet.
+    #[test]
+    fn test_string_with_allowed_characters() {
+        let mut rng = Random::with_seed(31);
+        let lower = "abcdefghijklmnopqrstuvwxyz";
+        let upper = lower.to_uppercase();
+        let digits = "0123456789";
+        let cases = [
+            (
+                StringCharset::Alphanumeric,
+                format!("{}{}{}", digits, lower, upper),
+            ),
+            (StringCharset::Lowercase, lower.to_string()),
+            (StringCharset::Hex, format!("{}abcdef", digits)),
+            (
+                StringCharset::Base64Url,
+                format!("{}{}{}-_", upper, lower, digits),
+            ),
+            (
+                StringCharset::Custom(vec!['x', 'é', '🎲']),
+                "xé🎲".into(),
+            ),
+        ];
+        for (charset, allowed) in cases {
+            let text = rng.string_with(500, charset.clone());
+            assert_eq!(text.chars().count(), 500);
+            assert!(
+                text.chars().all(|c| allowed.contains(c)),
+                "{:?}",
+                charset
+            );
+        }
+    }
+
+    /// Tests that `string_with` is reproducible under a fixed seed for every variant.
+    #[test]
+    fn test_string_with_reproducible() {
+        let charsets = [
+            StringCharset::Alphanumeric,
+            StringCharset::Lowercase,
+            StringCharset::Hex,
+            StringCharset::Base64Url,
+            StringCharset::Custom(vec!['0', '1']),
+        ];
+        for charset in charsets {
+            let mut a = Random::with_seed(8);
+            let mut b = Random::with_seed(8);
+            assert_eq!(
+                a.string_with(64, charset.clone()),
+                b.string_with(64, charset)
+            );
+        }
+    }
+
+    /// Tests that `string_with` reaches every character of a small alphabet.
+    #[test]
+    fn test_string_with_covers_alphabet() {
+        let mut rng = Random::with_seed(3);
+        let text = rng.string_with(2_000, StringCharset::Base64Url);
+        let distinct: std::collections::HashSet<char> =
+            text.chars().collect();
+        assert_eq!(distinct.len(), 64);
+    }
+
+    /// Tests that `string_with` panics on an empty custom alphabet.
+    #[test]
+    #[should_panic(
+        expected = "charset must not be empty for string_with"
+    )]
+    fn test_string_with_empty_custom() {
+        let mut rng = Random::new();
+        let _ = rng.string_with(4, StringCharset::Custom(Vec::new()));
+    }
+
+    // Default construction tests
+
+    /// Tests that `Random::default` produces the reference MT19937 output under `deterministic-default`.
+    #[cfg(feature = "deterministic-default")]
+    #[test]
+    fn test_deterministic_default() {
+        let mut rng = Random::default();
+        assert_eq!(rng.rand(), 3499211612);
+        assert_eq!(
+            Random::default().state(),
+            Random::unseeded().state()
+        );
+    }
+
+    // Pool draining tests
+
+    /// Tests that `drain_sample` moves exactly the drawn elements out of the pool.
+    #[test]
+    fn test_drain_sample_removes_drawn_elements() {
+        let mut rng = Random::with_seed(17);
+        let original: Vec<u32> = (0..20).collect();
+        let mut pool = original.clone();
+        let drawn = rng.drain_sample(&mut pool, 7);
+        assert_eq!(drawn.len(), 7);
+        assert_eq!(pool.len(), 13);
+        let mut combined: Vec<u32> =
+            drawn.iter().chain(pool.iter()).copied().collect();
+        combined.sort_unstable();
+        assert_eq!(combined, original);
+    }
+
+    /// Tests that `drain_sample` is reproducible under a fixed seed.
+    #[test]
+    fn test_drain_sample_reproducible() {
+        let mut a = Random::with_seed(17);
+        let mut b = Random::with_seed(17);
+        let mut pool_a: Vec<u32> = (0..20).collect();
+        let mut pool_b = pool_a.clone();
+        assert_eq!(
+            a.drain_sample(&mut pool_a, 7),
+            b.drain_sample(&mut pool_b, 7)
+        );
+        assert_eq!(pool_a, pool_b);
+    }
+
+    /// Tests that `drain_sample` clamps `k` to the pool size.
+    #[test]
+    fn test_drain_sample_clamps_k() {
+        let mut rng = Random::with_seed(17);
+        let mut pool = vec!['a', 'b', 'c'];
+        let mut drawn = rng.drain_sample(&mut pool, 10);
+        assert!(pool.is_empty());
+        drawn.sort_unstable();
+        assert_eq!(drawn, ['a', 'b', 'c']);
+        assert!(rng.drain_sample(&mut pool, 1).is_empty());
+    }
+
+    // Correlated normal tests
+
+    /// Tests that `correlated_normals` reaches the target correlation with standard normal marginals.
+    #[test]
+    fn test_correlated_normals_statistics() {
+        let mut rng = Random::with_seed(77);
+        let n = 100_000;
+        for rho in [-0.9, 0.0, 0.5, 1.0] {
+            let pairs: Vec<(f64, f64)> =
+                (0..n).map(|_| rng.correlated_normals(rho)).collect();
+            let mean = |f: &dyn Fn(&(f64, f64)) -> f64| {
+                pairs.iter().map(f).sum::<f64>() / n as f64
+            };
+            let (mean1, mean2) = (mean(&|p| p.0), mean(&|p| p.1));
+            let var1 = mean(&|p| (p.0 - mean1).powi(2));
+            let var2 = mean(&|p| (p.1 - mean2).powi(2));
+            let cov = mean(&|p| (p.0 - mean1) * (p.1 - mean2));
+            assert!(mean1.abs() < 0.02 && mean2.abs() < 0.02);
+            assert!(
+                (var1 - 1.0).abs() < 0.03 && (var2 - 1.0).abs() < 0.03
+            );
+            let correlation = cov / (var1 * var2).sqrt();
+            assert!((correlation - rho).abs() < 0.02, "rho {}", rho);
+        }
+    }
+
+    /// Tests that `correlated_normals` panics when `rho` is out of range.
+    #[test]
+    #[should_panic(
+        expected = "rho must be between -1.0 and 1.0 for correlated_normals"
+    )]
+    fn test_correlated_normals_invalid_rho() {
+        let mut rng = Random::new();
+        let _ = rng.correlated_normals(1.5);
+    }
+
+    // Entry state tests
+
+    /// Tests that `rand` twists the existing state without reseeding when `mti` is 624.
+    #[test]
+    fn test_rand_entry_state_exhausted() {
+        let mut rng = Random::with_seed(42);
+        assert_eq!(rng.mti, 624);
+        let mut expected = rng.clone();
+        expected.twist();
+        assert_eq!(
+            rng.rand(),
+            Random::temper(expected.mt[0], &rng.params)
+        );
+        assert_eq!(rng.mti, 1);
+    }
+
+    /// Tests that `rand` seeds with 5489 before twisting when `mti` is 625.
+    #[test]
+    fn test_rand_entry_state_unseeded() {
+        let mut rng = Random::with_seed(42);
+        rng.mt = [7; 624];
+        rng.mti = 625;
+        assert_eq!(rng.rand(), 3499211612);
+        assert_eq!(rng.state(), {
+            let mut reference = Random::unseeded();
+            let _ = reference.rand();
+            reference.state()
+        });
+    }
+
+    /// Tests that `rand` treats an `mti` of 626 as exhausted rather than unseeded.
+    #[test]
+    fn test_rand_entry_state_past_sentinel() {
+        let mut rng = Random::with_seed(42);
+        let mut exhausted = rng.clone();
+        rng.mti = 626;
+        assert_eq!(rng.rand(), exhausted.rand());
+        assert_eq!(rng.mti, 1);
+    }
+
+    // Leapfrog tests
+
+    /// Tests that interleaving leapfrogged generators reproduces the master stream.
+    #[test]
+    fn test_leapfrog_reconstructs_master() {
+        for parts in 1..=5 {
+            let mut master = Random::with_seed(1234);
+            let mut generators = Random::leapfrog(&master, parts);
+            assert_eq!(generators.len(), parts);
+            // Run well past a twist boundary so the skips cross blocks.
+            for _ in 0..1_500 / parts {
+                for generator in generators.iter_mut() {
+                    assert_eq!(generator.rand(), master.rand());
+                }
+            }
+        }
+
+        // A never-seeded master is seeded with the default seed before it is split.
+        let mut unseeded = Random::with_seed(1);
+        unseeded.set_mti(625);
+        let mut generators = Random::leapfrog(&unseeded, 2);
+        let mut master = Random::unseeded();
+        for _ in 0..700 {
+            for generator in generators.iter_mut() {
+                assert_eq!(generator.rand(), master.rand());
+            }
+        }
+    }
+
+    /// Tests that a leapfrogged generator can be split again.
+    #[test]
+    fn test_leapfrog_nested() {
+        let mut master = Random::unseeded();
+        let halves = Random::leapfrog(&master, 2);
+        let mut quarters: Vec<Leapfrog> =
+            halves.iter().flat_map(|half| half.leapfrog(2)).collect();
+        assert!(quarters.iter().all(|quarter| quarter.stride() == 4));
+        // Quarters are ordered [0, 2, 1, 3] by master offset.
+        let order = [0, 2, 1, 3];
+        for _ in 0..300 {
+            for &index in &order {
+                assert_eq!(quarters[index].rand(), master.rand());
+            }
+        }
+    }
+
+    /// Tests that saving and restoring a leapfrogged generator keeps its stride.
+    #[test]
+    fn test_leapfrog_round_trip() {
+        let mut part =
+            Random::leapfrog(&Random::with_seed(1), 3).remove(1);
+        let _ = part.rand();
+        let json = serde_json::to_string(&part).unwrap();
+        let mut restored: Leapfrog =
+            serde_json::from_str(&json).unwrap();
+        let mut cloned = part.clone();
+        for _ in 0..700 {
+            let expected = part.rand();
+            assert_eq!(restored.rand(), expected);
+            assert_eq!(cloned.rand(), expected);
+        }
+    }
+
+    /// Tests that unwrapping a leapfrogged generator continues the master stream without skipping.
+    #[test]
+    fn test_leapfrog_into_inner() {
+        let mut master = Random::with_seed(1);
+        let mut part = Random::leapfrog(&master, 3).remove(1);
+        let _ = master.rand();
+        assert_eq!(part.rand(), master.rand());
+        // The part has skipped the two words that belong to its siblings.
+        let _ = master.rand();
+        let _ = master.rand();
+        let mut inner = part.into_inner();
+        for _ in 0..10 {
+            assert_eq!(inner.rand(), master.rand());
+        }
+    }
+
+    /// Tests that leapfrogged generators work as `RngCore` sources over the strided stream.
+    #[test]
+    fn test_leapfrog_rng_core() {
+        use rand::Rng;
+
+        let mut master = Random::with_seed(7);
+        let mut parts = Random::leapfrog(&master, 2);
+        let mut expected = vec![0u8; 80];
+        master.fill_bytes(&mut expected);
+        let mut interleaved = Vec::with_capacity(80);
+        for _ in 0..10 {
+            for part in parts.iter_mut() {
+                let mut word = [0u8; 4];
+                part.fill_bytes(&mut word);
+                interleaved.extend_from_slice(&word);
+            }
+        }
+        assert_eq!(interleaved, expected);
+
+        let mut master = Random::with_seed(7);
+        let mut parts = Random::leapfrog(&master, 2);
+        let outputs: Vec<u32> = (0..4).map(|_| master.rand()).collect();
+        assert_eq!(
+            parts[0].next_u64(),
+            (u64::from(outputs[0]) << 32) | u64::from(outputs[2])
+        );
+        assert_eq!(parts[1].next_u32(), outputs[1]);
+        assert_eq!(parts[1].next_u32(), outputs[3]);
+        assert!(parts[1].gen_range(0..10) < 10);
+    }
+
+    /// Tests that `leapfrog` panics when `parts` is zero.
+    #[test]
+    #[should_panic(expected = "parts must be positive for leapfrog")]
+    fn test_leapfrog_zero_parts() {
+        let _ = Random::leapfrog(&Random::new(), 0);
+    }
+
+    // Block view tests
+
+    /// Tests that `current_block` exposes the words that subsequent `rand` calls temper and emit.
+    #[test]
+    fn test_current_block_matches_rand() {
+        let mut rng = Random::with_seed(42);
+        let _ = rng.rand();
+        let (block, mti) = rng.current_block();
+        assert_eq!(mti, 1);
+        assert_eq!(*block, rng.state().0);
+        let params = rng.params;
+        let expected: Vec<u32> = block[mti..]
+            .iter()
+            .map(|&word| Random::temper(word, &params))
+            .collect();
+        let emitted: Vec<u32> =
+            (0..expected.len()).map(|_| rng.rand()).collect();
+        assert_eq!(emitted, expected);
+        assert_eq!(rng.current_block().1, 624);
+    }
+
+    // Rademacher tests
+
+    /// Tests that `random_sign` only returns ±1 and averages close to zero.
+    #[test]
+    fn test_random_sign_mean() {
+        let mut rng = Random::with_seed(13);
+        let n = 100_000;
+        let mut sum = 0i64;
+        for _ in 0..n {
+            let sign = rng.random_sign();
+            assert!(sign == -1 || sign == 1);
+            sum += i64::from(sign);
+        }
+        assert!((sum as f64 / n as f64).abs() < 0.01);
+    }
+
+    /// Tests that `rademacher_vec` has the right length and values, reproducibly under a fixed seed.
+    #[test]
+    fn test_rademacher_vec() {
+        let mut a = Random::with_seed(13);
+        let mut b = Random::with_seed(13);
+        let values = a.rademacher_vec(1_000);
+        assert_eq!(values.len(), 1_000);
+        assert!(values.iter().all(|&x| x == -1 || x == 1));
+        assert!(values.contains(&-1) && values.contains(&1));
+        let expected: Vec<i32> =
+            (0..1_000).map(|_| b.random_sign()).collect();
+        assert_eq!(values, expected);
+        assert!(a.rademacher_vec(0).is_empty());
+    }
+
+    // Checked seeding tests
+
+    /// Tests that `checked_seed` accepts ordinary and edge seeds and matches `seed`.
+    #[test]
+    fn test_checked_seed_ok() {
+        for seed in [0, 1, 42, 5489, 0x8000_0000, u32::MAX] {
+            let mut checked = Random::new();
+            assert!(checked.checked_seed(seed).is_ok());
+            let mut plain = Random::new();
+            plain.seed(seed);
+            assert_eq!(checked.state(), plain.state());
+            assert_eq!(checked.rand(), plain.rand());
+        }
+    }
+
+    // Simplex tests
+
+    /// Tests that `random_simplex` points are valid and have component means near `1/n`.
+    #[test]
+    fn test_random_simplex_statistics() {
+        let mut rng = Random::with_seed(61);
+        for n in [1, 2, 5] {
+            let trials = 20_000;
+            let mut sums = vec![0.0; n];
+            for _ in 0..trials {
+                let point = rng.random_simplex(n);
+                assert_eq!(point.len(), n);
+                assert!(point.iter().all(|&x| x >= 0.0));
+                assert!(
+                    (point.iter().sum::<f64>() - 1.0).abs() < 1e-12
+                );
+                for (sum, x) in sums.iter_mut().zip(point) {
+                    *sum += x;
+                }
+            }
+            for sum in sums {
+                let mean = sum / trials as f64;
+                assert!(
+                    (mean - 1.0 / n as f64).abs() < 0.01,
+                    "n {}",
+                    n
+                );
+            }
+        }
+    }
+
+    /// Tests that `random_simplex` panics when `n` is zero.
+    #[test]
+    #[should_panic(
+        expected = "n must be at least 1 for random_simplex"
+    )]
+    fn test_random_simplex_zero() {
+        let mut rng = Random::new();
+        let _ = rng.random_simplex(0);
+    }
+
+    // Full span range tests
+
+    /// Tests that `range(i32::MIN, i32::MAX)` draws across the full span without overflowing.
+    #[test]
+    fn test_range_full_span() {
+        let mut rng = Random::with_seed(3);
+        let mut reference = Random::with_seed(3);
+        let (mut negative, mut positive) = (false, false);
+        for _ in 0..10_000 {
+            let value = rng.range(i32::MIN, i32::MAX);
+            // Over the full span, each value is the raw output shifted by `i32::MIN`.
+            let raw = reference.rand();
+            assert_eq!(
+                value,
+                (i64::from(i32::MIN) + i64::from(raw)) as i32
+            );
+            negative |= value < 0;
+            positive |= value > 0;
+        }
+        assert!(negative && positive);
+    }
+
+    /// Tests that both extremes of the full `i32` span are reachable.
+    #[test]
+    fn test_range_full_span_extremes() {
+        let mut rng = Random::with_seed(3);
+        let _ = rng.rand();
+        rng.mt[1] = untemper(0);
+        rng.mt[2] = untemper(u32::MAX);
+        assert_eq!(rng.range(i32::MIN, i32::MAX), i32::MIN);
+        assert_eq!(rng.range(i32::MIN, i32::MAX), i32::MAX);
+    }
+
+    /// Tests that ranges with a negative lower bound do not overflow and keep their mapping.
+    #[test]
+    fn test_int_negative_bounds() {
+        let mut rng = Random::with_seed(3);
+        let mut reference = Random::with_seed(3);
+        for _ in 0..1_000 {
+            let expected = -5 + (reference.rand() % 11) as i32;
+            assert_eq!(rng.int(-5, 5), expected);
+        }
+    }
+
+    // Channel streaming tests
+
+    /// Tests that `stream_to` delivers exactly `count` seeded values through a bounded channel.
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_stream_to_bounded_channel() {
+        let (tx, mut rx) = tokio::sync::mpsc::channel(4);
+        let mut rng = Random::with_seed(42);
+        let producer =
+            tokio::spawn(async move { rng.stream_to(tx, 1_000).await });
+        let mut received = Vec::new();
+        while let Some(value) = rx.recv().await {
+            received.push(value);
+        }
+        producer.await.unwrap().unwrap();
+        let mut reference = Random::with_seed(42);
+        let expected: Vec<u32> =
+            (0..1_000).map(|_| reference.rand()).collect();
+        assert_eq!(received, expected);
+    }
+
+    /// Tests that `stream_to` stops with an error once the receiver is dropped.
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_stream_to_receiver_dropped() {
+        let (tx, rx) = tokio::sync::mpsc::channel(1);
+        drop(rx);
+        let mut rng = Random::with_seed(42);
+        let result = rng.stream_to(tx, 10).await;
+        assert_eq!(result.unwrap_err().0, 1608637542);
+    }
+
+    // Generic integer tests
+
+    /// Tests that `gen_int` stays in range and covers every value of a small span uniformly.
+    #[test]
+    fn test_gen_int_small_widths() {
+        let mut rng = Random::with_seed(21);
+        let mut counts = [0u32; 10];
+        for _ in 0..100_000 {
+            let value: u8 = rng.gen_int(246, 255);
+            counts[usize::from(value - 246)] += 1;
+        }
+        assert!(counts.iter().all(|&c| (9_500..=10_500).contains(&c)));
+
+        let mut seen = [false; 7];
+        for _ in 0..1_000 {
+            let value: i16 = rng.gen_int(-3, 3);
+            assert!((-3..=3).contains(&value));
+            seen[(value + 3) as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    /// Tests that `gen_int` handles wide and full spans for `u32` and `i64`.
+    #[test]
+    fn test_gen_int_wide_widths() {
+        let mut rng = Random::with_seed(21);
+        let mut reference = Random::with_seed(21);
+        for _ in 0..1_000 {
+            assert_eq!(rng.gen_int(0u32, u32::MAX), reference.rand());
+        }
+        let (mut negative, mut positive) = (false, false);
+        let mut sum = 0.0;
+        for _ in 0..10_000 {
+            let value = rng.gen_int(i64::MIN, i64::MAX);
+            negative |= value < 0;
+            positive |= value > 0;
+            let offset =
+                rng.gen_int(-1_000_000_000_000i64, 1_000_000_000_000);
+            assert!((-1_000_000_000_000..=1_000_000_000_000)
+                .contains(&offset));
+            sum += offset as f64;
+        }
+        assert!(negative && positive);
+        assert!((sum / 10_000.0).abs() < 2e10);
+        assert_eq!(rng.gen_int(i128::MAX, i128::MAX), i128::MAX);
+        assert_eq!(rng.gen_int(u8::MIN, u8::MIN), 0);
+    }
+
+    /// Tests that `gen_int` panics when `min` exceeds `max`.
+    #[test]
+    #[should_panic(
+        expected = "min must be less than or equal to max for gen_int"
+    )]
+    fn test_gen_int_min_greater_than_max() {
+        let mut rng = Random::new();
+        let _ = rng.gen_int(5u32, 4);
+    }
+
+    // Last output tests
+
+    /// Tests that `last_output` matches the value returned by the preceding `rand` call.
+    #[cfg(feature = "counters")]
+    #[test]
+    fn test_last_output() {
+        let mut rng = Random::with_seed(42);
+        assert_eq!(rng.last_output(), None);
+        for _ in 0..1_000 {
+            let value = rng.rand();
+            assert_eq!(rng.last_output(), Some(value));
+            assert_eq!(rng.last_output(), Some(value));
+        }
+        let wide = rng.u64();
+        assert_eq!(rng.last_output(), Some(wide as u32));
+        let mut bytes = [0u8; 256];
+        rng.fill_bytes(&mut bytes);
+        let tail: [u8; 4] = bytes[252..].try_into().unwrap();
+        assert_eq!(rng.last_output(), Some(u32::from_le_bytes(tail)));
+    }
+
+    // Four-word key tests
+
+    /// Tests that `from_key` reproduces the published `mt19937ar.c` outputs for its reference key.
+    #[test]
+    fn test_from_key_reference_outputs() {
+        let mut rng = Random::from_key([0x123, 0x234, 0x345, 0x456]);
+        let expected = [
+            1067595299, 955945823, 477289528, 4107218783, 4228976476,
+            3344332714, 3355579695, 227628506, 810200273, 2591290167,
+        ];
+        for value in expected {
+            assert_eq!(rng.rand(), value);
+        }
+    }
+
+    /// Tests that `from_key` matches `seed_from_sources` with the same words.
+    #[test]
+    fn test_from_key_matches_seed_from_sources() {
+        let key = [1, 2, 3, 4];
+        let mut sourced = Random::new();
+        sourced.seed_from_sources(&key);
+        assert_eq!(Random::from_key(key).state(), sourced.state());
+    }
+
+    // Inclusive range tests
+
+    /// Tests that `random_range_inclusive` can return `u32::MAX`.
+    #[test]
+    fn test_random_range_inclusive_reaches_max() {
+        let mut rng = Random::with_seed(8);
+        let draws: Vec<u32> = (0..100)
+            .map(|_| rng.random_range_inclusive(u32::MAX - 1, u32::MAX))
+            .collect();
+        assert!(draws.contains(&u32::MAX));
+        assert!(draws.contains(&(u32::MAX - 1)));
+        assert!(draws.iter().all(|&v| v >= u32::MAX - 1));
+    }
+
+    /// Tests that `random_range_inclusive` covers the full span and degenerate ranges.
+    #[test]
+    fn test_random_range_inclusive_spans() {
+        let mut rng = Random::with_seed(8);
+        let mut reference = Random::with_seed(8);
+        assert_eq!(
+            rng.random_range_inclusive(0, u32::MAX),
+            reference.rand()
+        );
+        assert_eq!(rng.random_range_inclusive(7, 7), 7);
+    }
+
+    /// Tests that `random_range_inclusive` panics when `min` exceeds `max`.
+    #[test]
+    #[should_panic(
+        expected = "min must be less than or equal to max for random_range_inclusive"
+    )]
+    fn test_random_range_inclusive_invalid() {
+        let mut rng = Random::new();
+        let _ = rng.random_range_inclusive(2, 1);
+    }
+
+    // Frequency count tests
+
+    /// Tests that `sample_counts` bins `poisson(3.0)` with a mode of 2 or 3.
+    #[test]
+    fn test_sample_counts_poisson_mode() {
+        let mut rng = Random::with_seed(12);
+        let counts = rng.sample_counts(|rng| rng.poisson(3.0), 50_000);
+        assert_eq!(counts.values().sum::<usize>(), 50_000);
+        let (&mode, _) = counts
+            .iter()
+            .max_by_key(|&(&outcome, &count)| (count, outcome))
+            .unwrap();
+        assert!(mode == 2 || mode == 3);
+        // P(X = 3) for Poisson(3) is 4.5 * e^-3, about 0.224.
+        let p3 = counts[&3] as f64 / 50_000.0;
+        assert!((p3 - 0.224).abs() < 0.01);
+    }
+
+    /// Tests that `sample_counts` matches drawing the same outcomes by hand.
+    #[test]
+    fn test_sample_counts_reproducible() {
+        let mut a = Random::with_seed(12);
+        let mut b = Random::with_seed(12);
+        let counts =
+            a.sample_counts(|rng| u64::from(rng.rand() % 4), 100);
+        let mut expected = std::collections::HashMap::new();
+        for _ in 0..100 {
+            *expected.entry(u64::from(b.rand() % 4)).or_insert(0) += 1;
+        }
+        assert_eq!(counts, expected);
+        assert!(a.sample_counts(|rng| rng.u64(), 0).is_empty());
+    }
+
+    // Reseeding clone tests
+
+    /// Tests that cloning a `CloneReseed` diverges while the inner `Random` still clones identically.
+    #[test]
+    fn test_clone_reseed_diverges() {
+        let mut parent = CloneReseed::new(Random::with_seed(42));
+        let mut first = parent.clone();
+        let mut second = parent.clone();
+        let parent_values: Vec<u32> =
+            (0..8).map(|_| parent.rand()).collect();
+        let first_values: Vec<u32> =
+            (0..8).map(|_| first.rand()).collect();
+        let second_values: Vec<u32> =
+            (0..8).map(|_| second.rand()).collect();
+        assert_ne!(parent_values, first_values);
+        assert_ne!(first_values, second_values);
+        assert_eq!(parent_values[0], 1608637542);
+
+        let inner = parent.into_inner();
+        let mut plain = inner.clone();
+        assert_eq!(plain, inner);
+        let mut inner = inner;
+        assert_eq!(plain.rand(), inner.rand());
+    }
+
+    /// Tests that `CloneReseed` children are reproducible for the same parent state and clone order.
+    #[test]
+    fn test_clone_reseed_reproducible() {
+        let a = CloneReseed::from(Random::with_seed(7));
+        let b = CloneReseed::from(Random::with_seed(7));
+        for _ in 0..3 {
+            let (mut child_a, mut child_b) = (a.clone(), b.clone());
+            assert_eq!(child_a.rand(), child_b.rand());
+        }
+    }
+
+    // Sorted uniform tests
+
+    /// Tests that `sorted_uniforms` is sorted, in range, and has order-statistic means near `i/(n+1)`.
+    #[test]
+    fn test_sorted_uniforms_order_statistics() {
+        let mut rng = Random::with_seed(19);
+        let n = 9;
+        let trials = 20_000;
+        let mut sums = vec![0.0; n];
+        for _ in 0..trials {
+            let values = rng.sorted_uniforms(n);
+            assert_eq!(values.len(), n);
+            assert!(values.iter().all(|&x| x > 0.0 && x < 1.0));
+            assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+            for (sum, x) in sums.iter_mut().zip(values) {
+                *sum += x;
+            }
+        }
+        for (i, sum) in sums.iter().enumerate() {
+            let expected = (i + 1) as f64 / (n + 1) as f64;
+            assert!((sum / trials as f64 - expected).abs() < 0.005);
+        }
+        assert!(rng.sorted_uniforms(0).is_empty());
+    }
+
+    // Hex string tests
+
+    /// Tests that `random_hex` has the right length and alphabet and is reproducible.
+    #[test]
+    fn test_random_hex() {
+        let mut rng = Random::with_seed(42);
+        let mut reference = Random::with_seed(42);
+        for byte_len in [0, 1, 3, 16, 33] {
+            let hex = rng.random_hex(byte_len);
+            assert_eq!(hex.len(), 2 * byte_len);
+            assert!(hex.chars().all(
+                |c| c.is_ascii_digit() || ('a'..='f').contains(&c)
+            ));
+            assert_eq!(hex, reference.random_hex(byte_len));
+        }
+    }
+
+    /// Tests that `random_hex` encodes the `fill_bytes` stream, high nibble first.
+    #[test]
+    fn test_random_hex_encodes_bytes() {
+        let mut rng = Random::with_seed(42);
+        let mut reference = Random::with_seed(42);
+        let mut bytes = [0u8; 4];
+        reference.fill_bytes(&mut bytes);
+        let expected: String =
+            bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+        assert_eq!(rng.random_hex(4), expected);
+    }
+
+    // Configuration constructor tests
+
+    /// Tests that `from_config` seeds a generator from a matching config and rejects mismatched sizes.
+    #[test]
+    fn test_from_config_dimensions() {
+        let mut rng =
+            Random::from_config(&MersenneTwisterConfig::default(), 42)
+                .unwrap();
+        assert_eq!(rng.rand(), 1608637542);
+        let mismatched =
+            MersenneTwisterConfig::<397, 200>::new().unwrap();
+        let error = Random::from_config(&mismatched, 42).unwrap_err();
+        assert!(error.to_string().contains(
+            "n must be 624 to match the state array, but the config has n = 397"
+        ));
+    }
+
+    // Random walk tests
+
+    /// Tests that `random_walk` takes steps of exactly `step_size` and is reproducible.
+    #[test]
+    fn test_random_walk_discrete() {
+        let mut rng = Random::with_seed(4);
+        let mut reference = Random::with_seed(4);
+        let path = rng.random_walk(1_000, 0.25);
+        assert_eq!(path.len(), 1_001);
+        assert_eq!(path[0], 0.0);
+        assert!(path.windows(2).all(|pair| ((pair[1] - pair[0])
+            .abs()
+            - 0.25)
+            .abs()
+            < 1e-12));
+        assert_eq!(path, reference.random_walk(1_000, 0.25));
+        assert_eq!(rng.random_walk(0, 1.0), [0.0]);
+    }
+
+    /// Tests that `random_walk_normal` increments have the requested spread.
+    #[test]
+    fn test_random_walk_normal() {
+        let mut rng = Random::with_seed(4);
+        let path = rng.random_walk_normal(100_000, 2.0);
+        assert_eq!(path.len(), 100_001);
+        let increments: Vec<f64> =
+            path.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        let n = increments.len() as f64;
+        let mean = increments.iter().sum::<f64>() / n;
+        let variance =
+            increments.iter().map(|x| (x - mean).powi(2)).sum::<f64>()
+                / n;
+        assert!(mean.abs() < 0.03);
+        assert!((variance.sqrt() - 2.0).abs() < 0.03);
+    }
+
+    // Double precision tests
+
+    /// Tests that `double` uses all 53 bits of the mantissa.
+    #[test]
+    fn test_double_uses_low_mantissa_bits() {
+        let mut rng = Random::new();
+        rng.seed(42);
+        let mut low_bits = 0u64;
+        for _ in 0..1000 {
+            let x = rng.double();
+            assert!((0.0..1.0).contains(&x));
+            low_bits |=
+                (x * 9_007_199_254_740_992.0) as u64 & 0x1f_ffff;
+        }
+        assert_eq!(low_bits, 0x1f_ffff);
+    }
+
+    /// Tests the extreme values of `double`.
+    #[test]
+    fn test_double_extremes() {
+        let mut rng = Random::new();
+        rng.mt[0] = untemper(u32::MAX);
+        rng.mt[1] = untemper(u32::MAX);
+        rng.mt[2] = untemper(0);
+        rng.mt[3] = untemper(0);
+        rng.mti = 0;
+        assert_eq!(rng.double(), 1.0 - f64::EPSILON / 2.0);
+        assert_eq!(rng.double(), 0.0);
+    }
+
+    // Buffer reuse tests
+
+    /// Tests that `bytes_into` reuses a buffer without leaving stale bytes behind.
+    #[test]
+    fn test_bytes_into_reuses_buffer() {
+        let mut rng = Random::new();
+        rng.seed(42);
+        let mut reference = rng.clone();
+        let mut buf = Vec::new();
+        for &len in &[64, 3, 0, 17, 64, 1] {
+            rng.bytes_into(&mut buf, len);
+            assert_eq!(buf.len(), len);
+            assert_eq!(buf, reference.bytes(len));
+        }
+        assert!(buf.capacity() >= 64);
+    }
+
+    /// Tests that `string_into` reuses a buffer without leaving stale characters behind.
+    #[test]
+    fn test_string_into_reuses_buffer() {
+        let mut rng = Random::new();
+        rng.seed(42);
+        let mut reference = rng.clone();
+        let mut buf = String::from("stale contents");
+        for &len in &[32, 2, 0, 9, 32, 1] {
+            rng.string_into(&mut buf, len);
+            assert_eq!(buf.len(), len);
+            assert_eq!(buf, reference.string(len));
+        }
+        assert!(buf.capacity() >= 32);
+    }
+
+    // Output equality tests
+
+    /// Tests a case where `==` and `produces_same_as` disagree.
+    #[test]
+    fn test_produces_same_as_ignores_twist_position() {
+        let due = Random::with_seed(42);
+        let mut twisted = due.clone();
+        twisted.twist();
+        assert_eq!(due.mti, 624);
+        assert_eq!(twisted.mti, 0);
+        assert_ne!(due, twisted);
+        assert!(due.produces_same_as(&twisted, 2000));
+        assert_eq!(due.mti, 624);
+    }
+
+    /// Tests that `produces_same_as` detects generators with different outputs.
+    #[test]
+    fn test_produces_same_as_different_seeds() {
+        let a = Random::with_seed(1);
+        let b = Random::with_seed(2);
+        assert!(!a.produces_same_as(&b, 10));
+        assert!(a.produces_same_as(&b, 0));
+    }
+
+    // Matrix tests
+
+    /// Tests the shape, range and reproducibility of `random_matrix`.
+    #[test]
+    fn test_random_matrix() {
+        let mut rng = Random::with_seed(42);
+        let matrix = rng.random_matrix(7, 5);
+        assert_eq!(matrix.len(), 35);
+        assert!(matrix.iter().all(|&x| (0.0..1.0).contains(&x)));
+        assert_eq!(Random::with_seed(42).random_matrix(7, 5), matrix);
+        assert!(rng.random_matrix(0, 5).is_empty());
+    }
+
+    /// Tests the shape, moments and reproducibility of `normal_matrix`.
+    #[test]
+    fn test_normal_matrix() {
+        let mut rng = Random::with_seed(42);
+        let matrix = rng.normal_matrix(100, 100, 2.0, 0.5);
+        assert_eq!(matrix.len(), 10_000);
+        let mean = matrix.iter().sum::<f64>() / matrix.len() as f64;
+        assert!((mean - 2.0).abs() < 0.05);
+        assert_eq!(
+            Random::with_seed(42).normal_matrix(100, 100, 2.0, 0.5),
+            matrix
+        );
+    }
+
+    /// Tests that `random_matrix` rejects dimensions whose product overflows.
+    #[test]
+    #[should_panic(
+        expected = "rows * cols must fit in usize for random_matrix"
+    )]
+    fn test_random_matrix_overflow() {
+        let mut rng = Random::new();
+        let _ = rng.random_matrix(usize::MAX, 2);
+    }
+
+    // Coupon collector tests
+
+    /// Tests that the mean of `coupon_collector` approaches `n * H(n)`.
+    #[test]
+    fn test_coupon_collector_mean() {
+        let mut rng = Random::with_seed(42);
+        let n = 20;
+        let trials = 5000;
+        let expected: f64 =
+            (1..=n).map(|k| f64::from(n) / f64::from(k)).sum();
+        let total: u64 =
+            (0..trials).map(|_| rng.coupon_collector(n)).sum();
+        let mean = total as f64 / f64::from(trials);
+        assert!((mean - expected).abs() < 0.03 * expected);
+    }
+
+    /// Tests that `coupon_collector` is reproducible and bounded below by `n`.
+    #[test]
+    fn test_coupon_collector_reproducible() {
+        let mut a = Random::with_seed(7);
+        let mut b = Random::with_seed(7);
+        for _ in 0..100 {
+            let draws = a.coupon_collector(10);
+            assert!(draws >= 10);
+            assert_eq!(draws, b.coupon_collector(10));
+        }
+        assert_eq!(a.coupon_collector(1), 1);
+    }
+
+    /// Tests that `coupon_collector` rejects zero coupons.
+    #[test]
+    #[should_panic(
+        expected = "n must be positive for coupon_collector"
+    )]
+    fn test_coupon_collector_zero() {
+        let mut rng = Random::new();
+        let _ = rng.coupon_collector(0);
+    }
+
+    // Bernoulli trials tests
+
+    /// Tests that the mean of `bernoulli_trials` approaches `n * p` on every code path.
+    #[test]
+    fn test_bernoulli_trials_mean() {
+        let mut rng = Random::with_seed(42);
+        let trials = 20_000;
+        for &(n, p) in
+            &[(10, 0.5), (40, 0.3), (33, 0.5), (1000, 0.2), (500, 0.9)]
+        {
+            let total: u64 = (0..trials)
+                .map(|_| u64::from(rng.bernoulli_trials(n, p)))
+                .sum();
+            let mean = total as f64 / f64::from(trials);
+            let expected = f64::from(n) * p;
+            let sd = (expected * (1.0 - p) / f64::from(trials)).sqrt();
+            assert!(
+                (mean - expected).abs() < 5.0 * sd,
+                "n = {}, p = {}: mean {} vs {}",
+                n,
+                p,
+                mean,
+                expected
+            );
+        }
+    }
+
+    /// Tests the edge cases and reproducibility of `bernoulli_trials`.
+    #[test]
+    fn test_bernoulli_trials_edges_and_reproducibility() {
+        let mut rng = Random::with_seed(7);
+        assert_eq!(rng.bernoulli_trials(100, 0.0), 0);
+        assert_eq!(rng.bernoulli_trials(100, 1.0), 100);
+        assert_eq!(rng.bernoulli_trials(0, 0.5), 0);
+        let mut a = Random::with_seed(7);
+        let mut b = Random::with_seed(7);
+        for &n in &[5, 64, 65, 10_000] {
+            let count = a.bernoulli_trials(n, 0.4);
+            assert!(count <= n);
+            assert_eq!(count, b.bernoulli_trials(n, 0.4));
+        }
+    }
+
+    /// Tests that fair `bernoulli_trials` read the buffered bit stream with the `bool(0.5)` convention.
+    #[test]
+    fn test_bernoulli_trials_fair_bit_buffered() {
+        for seed in 0..32 {
+            let mut rng = Random::with_seed(seed);
+            let expected = u32::from(Random::with_seed(seed).bool(0.5));
+            assert_eq!(rng.bernoulli_trials(1, 0.5), expected);
+        }
+
+        // Three calls of 10, 12 and 10 trials consume exactly one word, one bit per trial.
+        let mut rng = Random::with_seed(42);
+        let word = Random::with_seed(42).rand();
+        let counts = [10, 12, 10].map(|n| rng.bernoulli_trials(n, 0.5));
+        assert_eq!(counts.iter().sum::<u32>(), word.count_zeros());
+        assert_eq!(counts[0], 10 - (word >> 22).count_ones());
+        assert_eq!(rng.mti(), 1);
+        assert_eq!(rng.bits_be(32), Random::with_seed(42).u64() as u32);
+    }
+
+    /// Tests that `bernoulli_trials` rejects probabilities outside [0, 1].
+    #[test]
+    #[should_panic(
+        expected = "p must be between 0.0 and 1.0 for bernoulli_trials"
+    )]
+    fn test_bernoulli_trials_invalid_p() {
+        let mut rng = Random::new();
+        let _ = rng.bernoulli_trials(10, 1.5);
+    }
+
+    // Seeding consistency tests
+
+    /// Tests that `seed` reproduces the reference MT19937 `init_genrand` state and outputs.
+    #[test]
+    fn test_seed_matches_reference_init_genrand() {
+        let mut rng = Random::new();
+        rng.seed(1);
+        assert_eq!(&rng.mt[..3], &[1, 1812433254, 3713160357]);
+        assert_eq!(rng, Random::with_seed(1));
+        assert_eq!(rng.rand(), 1791095845);
+        assert_eq!(rng.rand(), 4282876139);
+
+        rng.seed(5489);
+        assert_eq!(&rng.mt[..3], &[5489, 1301868182, 2938499221]);
+        assert_eq!(rng.mt, Random::with_seed(5489).mt);
+        assert_eq!(rng.rand(), 3499211612);
+        assert_eq!(rng.rand(), 581869302);
+    }
+
+    // Antithetic sampling tests
+
+    /// Tests that antithetic pairs are complementary uniforms.
+    #[test]
+    fn test_antithetic_uniform_pair_sums_to_one() {
+        let mut rng = Random::with_seed(42);
+        for _ in 0..1000 {
+            let (u, v) = rng.antithetic_uniform_pair();
+            assert!(u > 0.0 && u < 1.0);
+            assert!(v > 0.0 && v < 1.0);
+            assert!((u + v - 1.0).abs() < 1e-15);
+        }
+    }
+
+    /// Tests that antithetic pairs reduce the variance of a Monte Carlo estimate of the integral of
+    /// `exp(x)` over [0, 1].
+    #[test]
+    fn test_antithetic_uniform_pair_reduces_variance() {
+        fn variance(values: &[f64]) -> f64 {
+            let mean = values.iter().sum::<f64>() / values.len() as f64;
+            values.iter().map(|x| (x - mean).powi(2)).sum::<f64>()
+                / (values.len() - 1) as f64
+        }
+        let mut rng = Random::with_seed(42);
+        let antithetic: Vec<f64> = (0..10_000)
+            .map(|_| {
+                let (u, v) = rng.antithetic_uniform_pair();
+                (u.exp() + v.exp()) / 2.0
+            })
+            .collect();
+        let independent: Vec<f64> = (0..10_000)
+            .map(|_| {
+                (rng.f64_open().exp() + rng.f64_open().exp()) / 2.0
+            })
+            .collect();
+        let exact = 1f64.exp() - 1.0;
+        let mean = antithetic.iter().sum::<f64>() / 10_000.0;
+        assert!((mean - exact).abs() < 0.005);
+        assert!(variance(&antithetic) < variance(&independent) / 10.0);
+    }
+
+    // Stratified sampling tests
+
+    /// Tests that every stratified uniform falls in its own stratum.
+    #[test]
+    fn test_stratified_uniforms_strata() {
+        let mut rng = Random::with_seed(42);
+        for &n in &[1, 7, 100, 1000] {
+            let values = rng.stratified_uniforms(n);
+            assert_eq!(values.len(), n);
+            for (i, &x) in values.iter().enumerate() {
+                assert!(x >= i as f64 / n as f64);
+                assert!(x < (i + 1) as f64 / n as f64);
+            }
+        }
+        assert!(rng.stratified_uniforms(0).is_empty());
+    }
+
+    /// Tests that stratified uniforms have a lower discrepancy than independent uniforms.
+    #[test]
+    fn test_stratified_uniforms_discrepancy() {
+        fn discrepancy(mut values: Vec<f64>) -> f64 {
+            values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            let n = values.len() as f64;
+            values
+                .iter()
+                .enumerate()
+                .map(|(i, &x)| {
+                    (x - i as f64 / n).max((i + 1) as f64 / n - x)
+                })
+                .fold(0.0, f64::max)
+        }
+        let mut rng = Random::with_seed(42);
+        let n = 200;
+        let mut stratified = 0.0;
+        let mut independent = 0.0;
+        for _ in 0..50 {
+            stratified += discrepancy(rng.stratified_uniforms(n));
+            independent +=
+                discrepancy((0..n).map(|_| rng.f64()).collect());
+        }
+        assert!(stratified / 50.0 <= 1.0 / n as f64);
+        assert!(stratified < independent / 5.0);
+    }
+
+    // Bit stream tests
+
+    /// Tests that `bits_le` and `bits_be` return reversed bit patterns from the same seed.
+    #[test]
+    fn test_bits_le_reverses_bits_be() {
+        for &n in &[1u8, 5, 16, 31, 32] {
+            let mut be = Random::with_seed(42);
+            let mut le = Random::with_seed(42);
+            for _ in 0..50 {
+                let expected = be.bits_be(n).reverse_bits() >> (32 - n);
+                assert_eq!(le.bits_le(n), expected);
+            }
+        }
+        assert_eq!(Random::with_seed(42).bits_le(0), 0);
+    }
+
+    /// Tests that partial bit draws buffer the remaining bits of each word.
+    #[test]
+    fn test_bits_be_buffers_partial_words() {
+        let mut words = Random::with_seed(7);
+        let first = words.rand();
+        let second = words.rand();
+
+        let mut rng = Random::with_seed(7);
+        assert_eq!(rng.bits_be(4), first >> 28);
+        assert_eq!(rng.bits_be(0), 0);
+        assert_eq!(rng.bits_be(20), (first >> 8) & 0xf_ffff);
+        // Crosses into the second word: the last 8 bits of `first` then the top 4 of `second`.
+        assert_eq!(
+            rng.bits_be(12),
+            ((first & 0xff) << 4) | (second >> 28)
+        );
+        assert_eq!(rng.bits_be(28), second & 0x0fff_ffff);
+        assert_eq!(rng.rand(), words.rand());
+
+        let _ = rng.bits_be(3);
+        rng.seed(7);
+        assert_eq!(rng.bits_be(32), first);
+    }
+
+    /// Tests that `bits_be` rejects more than 32 bits.
+    #[test]
+    #[should_panic(expected = "n must be at most 32 for bits_be")]
+    fn test_bits_be_too_many_bits() {
+        let mut rng = Random::new();
+        let _ = rng.bits_be(33);
+    }
+
+    // Order statistic tests
+
+    /// Tests that the means of `min_of_uniforms` and `max_of_uniforms` approach `1/(n+1)` and
+    /// `n/(n+1)`.
+    #[test]
+    fn test_extreme_uniform_means() {
+        let mut rng = Random::with_seed(42);
+        let trials = 20_000;
+        for &n in &[1u32, 4, 50] {
+            let expected_min = 1.0 / f64::from(n + 1);
+            let min_mean = (0..trials)
+                .map(|_| rng.min_of_uniforms(n))
+                .sum::<f64>()
+                / f64::from(trials);
+            let max_mean = (0..trials)
+                .map(|_| rng.max_of_uniforms(n))
+                .sum::<f64>()
+                / f64::from(trials);
+            assert!((min_mean - expected_min).abs() < 0.01);
+            assert!((max_mean - (1.0 - expected_min)).abs() < 0.01);
+        }
+    }
+
+    /// Tests that the extreme samplers consume exactly one uniform draw.
+    #[test]
+    fn test_extreme_uniforms_single_draw() {
+        let mut rng = Random::with_seed(7);
+        let mut reference = rng.clone();
+        let u = reference.f64();
+        assert_eq!(rng.max_of_uniforms(3), u.powf(1.0 / 3.0));
+        assert_eq!(rng, reference);
+        let u = reference.f64();
+        assert_eq!(
+            rng.min_of_uniforms(3),
+            1.0 - (1.0 - u).powf(1.0 / 3.0)
+        );
+        assert_eq!(rng, reference);
+    }
+
+    /// Tests that `min_of_uniforms` rejects an empty sample.
+    #[test]
+    #[should_panic(expected = "n must be positive for min_of_uniforms")]
+    fn test_min_of_uniforms_zero() {
+        let mut rng = Random::new();
+        let _ = rng.min_of_uniforms(0);
+    }
+
+    // Distribution moment tests
+
+    /// Tests that `distribution_mean` reports the mean and variance of `poisson(3.0)`.
+    #[test]
+    fn test_distribution_mean_poisson() {
+        let mut rng = Random::with_seed(42);
+        let (mean, variance) =
+            rng.distribution_mean(|rng| rng.poisson_f64(3.0), 200_000);
+        assert!((mean - 3.0).abs() < 0.02);
+        assert!((variance - 3.0).abs() < 0.05);
+    }
+
+    /// Tests that `distribution_mean` stays accurate when the mean dwarfs the spread.
+    #[test]
+    fn test_distribution_mean_large_offset() {
+        let mut rng = Random::with_seed(42);
+        let (mean, variance) =
+            rng.distribution_mean(|rng| 1e9 + rng.f64(), 1_000_000);
+        assert!((mean - (1e9 + 0.5)).abs() < 0.01);
+        assert!((variance - 1.0 / 12.0).abs() < 0.001);
+        assert_eq!(rng.distribution_mean(|_| 4.0, 1), (4.0, 0.0));
+    }
+
+    /// Tests that `bytes_heapless` respects the capacity and matches `bytes`.
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn test_bytes_heapless_matches_bytes() {
+        for &len in &[0, 5, 16, 100] {
+            let mut rng = Random::with_seed(42);
+            let mut reference = Random::with_seed(42);
+            let buf = rng.bytes_heapless::<16>(len);
+            assert_eq!(buf.len(), len.min(16));
+            assert_eq!(&buf[..], &reference.bytes(len.min(16))[..]);
+            assert_eq!(rng, reference);
+        }
+    }
+
+    // Hashable seeding tests
+
+    /// Tests that `from_hashable` seeds identically for equal values and differently for distinct ones.
+    #[test]
+    fn test_from_hashable() {
+        #[derive(Hash)]
+        struct Experiment {
+            name: &'static str,
+            run: u32,
+        }
+        let a = Experiment {
+            name: "sweep",
+            run: 1,
+        };
+        let b = Experiment {
+            name: "sweep",
+            run: 1,
+        };
+        let c = Experiment {
+            name: "sweep",
+            run: 2,
+        };
+        assert_eq!(
+            Random::from_hashable(&a),
+            Random::from_hashable(&b)
+        );
+        assert_ne!(
+            Random::from_hashable(&a),
+            Random::from_hashable(&c)
+        );
+
+        let mut rng1 = Random::from_hashable(&("path", 7u64));
+        let mut rng2 = Random::from_hashable(&("path", 7u64));
+        assert_eq!(rng1.rand(), rng2.rand());
+        assert_ne!(
+            Random::from_hashable(&1u64).rand(),
+            Random::from_hashable(&2u64).rand()
+        );
+    }
+
+    /// Tests that the `from_hashable` hasher is pinned to little-endian FNV-1a.
+    #[test]
+    fn test_from_hashable_is_pinned() {
+        let mut rng = Random::from_hashable(&0x0102_0304u32);
+        let mut reference = Random::new();
+        // FNV-1a of the little-endian bytes [4, 3, 2, 1].
+        let hash = [4u8, 3, 2, 1].iter().fold(
+            0xcbf2_9ce4_8422_2325u64,
+            |hash, &byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            },
+        );
+        reference.seed_from_bytes(&hash.to_le_bytes());
+        assert_eq!(rng.rand(), reference.rand());
+        assert_eq!(
+            Random::from_hashable(&5usize),
+            Random::from_hashable(&5u64)
+        );
+    }
+
+    // Test vector tests
+
+    /// Tests that `test_vector` pins the reference MT19937 outputs.
+    #[test]
+    fn test_test_vector_reference_values() {
+        assert_eq!(Random::test_vector(42, 1)[0], 1608637542);
+        assert_eq!(
+            Random::test_vector(42, 5),
+            vec![
+                1608637542, 3421126067, 4083286876, 787846414,
+                3143890026
+            ]
+        );
+        assert_eq!(
+            Random::test_vector(5489, 3),
+            vec![3499211612, 581869302, 3890346734]
+        );
+        assert!(Random::test_vector(42, 0).is_empty());
+
+        let mut rng = Random::new();
+        rng.seed(42);
+        let expected: Vec<u32> =
+            (0..1000).map(|_| rng.rand()).collect();
+        assert_eq!(Random::test_vector(42, 1000), expected);
+    }
+
+    /// Tests that `portable-math` reproduces a pinned, bit-exact `normal` and `exponential` sequence.
+    #[cfg(feature = "portable-math")]
+    #[test]
+    fn test_portable_math_pinned_samples() {
+        let mut rng = Random::with_seed(42);
+        let normals: Vec<u64> =
+            (0..5).map(|_| rng.normal(0.0, 1.0).to_bits()).collect();
+        assert_eq!(
+            normals,
+            vec![
+                0x3ff55b5d1db3e650,
+                0xbfe49302d6647eb6,
+                0x3ff12cf5e4c4f40a,
+                0x3ff9745196d9af79,
+                0xbfd0d082670a104a,
+            ]
+        );
+        let exponentials: Vec<u64> =
+            (0..3).map(|_| rng.exponential(1.5).to_bits()).collect();
+        assert_eq!(
+            exponentials,
+            vec![
+                0x4004b5e2c69df458,
+                0x3f94db5fb3e13384,
+                0x3fbf4c792a5c7b7e
+            ]
+        );
+    }
+
+    // Unbiased choice tests
+
+    /// Tests with a chi-square statistic that `choose` selects uniformly from a length-7 slice.
+    #[test]
+    fn test_choose_uniform_chi_square() {
+        let mut rng = Random::with_seed(42);
+        let items = [0usize, 1, 2, 3, 4, 5, 6];
+        let draws = 70_000;
+        let mut counts = [0u32; 7];
+        for _ in 0..draws {
+            counts[*rng.choose(&items).unwrap()] += 1;
+        }
+        let expected = f64::from(draws) / 7.0;
+        let chi_square: f64 = counts
+            .iter()
+            .map(|&count| {
+                (f64::from(count) - expected).powi(2) / expected
+            })
+            .sum();
+        // The 99.9th percentile of the chi-square distribution with 6 degrees of freedom.
+        assert!(chi_square < 22.46, "chi-square {}", chi_square);
+    }
+
+    /// Tests that `choose` rejects biased draws and `choose_fast` consumes exactly one draw.
+    #[test]
+    fn test_choose_rejection_and_choose_fast_draws() {
+        let items = [0, 1, 2, 3, 4, 5, 6];
+        // 2^32 mod 7 is 4, so the four largest outputs are rejected by `choose`.
+        let mut rng = Random::new();
+        rng.mt[0] = untemper(u32::MAX);
+        rng.mt[1] = untemper(10);
+        rng.mti = 0;
+        assert_eq!(*rng.choose(&items).unwrap(), 3);
+        assert_eq!(rng.mti, 2);
+
+        rng.mti = 0;
+        assert_eq!(*rng.choose_fast(&items).unwrap(), 6);
+        assert_eq!(rng.mti, 1);
+        assert_eq!(*rng.choose_fast(&items).unwrap(), 0);
+        assert_eq!(rng.mti, 2);
+        assert!(rng.choose_fast::<i32>(&[]).is_none());
+        assert_eq!(rng.mti, 2);
+    }
+
+    // Optional seed tests
+
+    /// Tests that `from_optional_seed` reproduces `with_seed` for `Some` and varies for `None`.
+    #[test]
+    fn test_from_optional_seed() {
+        let mut seeded = Random::from_optional_seed(Some(42));
+        let mut expected = Random::with_seed(42);
+        for _ in 0..16 {
+            assert_eq!(seeded.rand(), expected.rand());
+        }
+
+        let first: Vec<u32> = {
+            let mut rng = Random::from_optional_seed(None);
+            (0..4).map(|_| rng.rand()).collect()
+        };
+        let second: Vec<u32> = {
+            let mut rng = Random::from_optional_seed(None);
+            (0..4).map(|_| rng.rand()).collect()
+        };
+        assert_ne!(first, second);
+    }
+
+    // Keyed shuffle tests
+
+    /// Tests that `shuffle_by_key` orders differently arranged slices with the same keys identically.
+    #[test]
+    fn test_shuffle_by_key_independent_of_initial_order() {
+        let mut forward: Vec<(u32, char)> = (0..20)
+            .map(|id| (id, char::from(b'a' + id as u8)))
+            .collect();
+        let mut reversed: Vec<(u32, char)> =
+            forward.iter().rev().copied().collect();
+        Random::with_seed(42)
+            .shuffle_by_key(&mut forward, |record| record.0);
+        Random::with_seed(42)
+            .shuffle_by_key(&mut reversed, |record| record.0);
+        assert_eq!(forward, reversed);
+
+        let mut sorted: Vec<(u32, char)> = (0..20)
+            .map(|id| (id, char::from(b'a' + id as u8)))
+            .collect();
+        Random::with_seed(42).shuffle(&mut sorted);
+        assert_eq!(forward, sorted);
+    }
+
+    // Generator seeding tests
+
+    /// Tests that `from_rng` seeds deterministically from a fixed `StepRng`.
+    #[test]
+    fn test_from_rng_step_rng() {
+        use rand::rngs::mock::StepRng;
+
+        let mut rng1 = Random::from_rng(&mut StepRng::new(1, 1));
+        let mut rng2 = Random::from_rng(&mut StepRng::new(1, 1));
+        for _ in 0..16 {
+            assert_eq!(rng1.rand(), rng2.rand());
+        }
+
+        let mut source = StepRng::new(1, 1);
+        let mut bytes = [0u8; 624 * 4];
+        source.fill_bytes(&mut bytes);
+        let mut expected = Random::new();
+        expected.seed_from_bytes(&bytes);
+        let mut seeded = Random::from_rng(&mut StepRng::new(1, 1));
+        assert_eq!(seeded.rand(), expected.rand());
+
+        let mut other = Random::from_rng(&mut StepRng::new(2, 1));
+        assert_ne!(
+            Random::from_rng(&mut StepRng::new(1, 1)).rand(),
+            other.rand()
+        );
+    }
 }
@@ -0,0 +1,298 @@
+// Copyright © 2023-2024 Random (VRD) library. All rights reserved.
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// This file is part of the `Random (VRD)` library, a Rust implementation of the Mersenne Twister RNG.
+// See LICENSE-APACHE.md and LICENSE-MIT.md in the repository root for full license information.
+
+//! Integration tests for the `samplers` module.
+
+#[cfg(test)]
+mod tests {
+    use vrd::random::Random;
+    use vrd::samplers::{
+        AliasTable, BernoulliSampler, GaussianMixture, PoissonSampler,
+        RollTable,
+    };
+
+    // Bernoulli sampler tests
+    /// Tests that the sampler's frequency of `true` matches `p`.
+    #[test]
+    fn test_bernoulli_sampler_frequency() {
+        let mut rng = Random::with_seed(42);
+        for &p in &[0.1, 0.3, 0.5, 0.9] {
+            let sampler = BernoulliSampler::new(p).unwrap();
+            let trues = (0..100_000)
+                .filter(|_| sampler.sample(&mut rng))
+                .count();
+            let frequency = trues as f64 / 100_000.0;
+            assert!((frequency - p).abs() < 0.01);
+        }
+    }
+
+    /// Tests that the sampler handles the extreme probabilities exactly.
+    #[test]
+    fn test_bernoulli_sampler_extremes() {
+        let mut rng = Random::with_seed(42);
+        let never = BernoulliSampler::new(0.0).unwrap();
+        let always = BernoulliSampler::new(1.0).unwrap();
+        for _ in 0..10_000 {
+            assert!(!never.sample(&mut rng));
+            assert!(always.sample(&mut rng));
+        }
+    }
+
+    /// Tests that each sample consumes exactly one `rand()` draw.
+    #[test]
+    fn test_bernoulli_sampler_single_draw() {
+        let mut rng = Random::with_seed(42);
+        let mut reference = Random::with_seed(42);
+        let sampler = BernoulliSampler::new(0.5).unwrap();
+        sampler.sample(&mut rng);
+        reference.rand();
+        assert_eq!(rng, reference);
+    }
+
+    /// Tests that probabilities outside `[0, 1]` are rejected at construction.
+    #[test]
+    fn test_bernoulli_sampler_invalid_probability() {
+        assert!(BernoulliSampler::new(-0.1).is_err());
+        assert!(BernoulliSampler::new(1.1).is_err());
+        assert!(BernoulliSampler::new(f64::NAN).is_err());
+    }
+
+    // Gaussian mixture tests
+    /// Tests that two well-separated components produce a bimodal histogram.
+    #[test]
+    fn test_gaussian_mixture_bimodal() {
+        let mut rng = Random::with_seed(42);
+        let mixture = GaussianMixture::new(vec![
+            (1.0, -10.0, 1.0),
+            (3.0, 10.0, 1.0),
+        ])
+        .unwrap();
+        let samples: Vec<f64> =
+            (0..10_000).map(|_| mixture.sample(&mut rng)).collect();
+        let left = samples
+            .iter()
+            .filter(|&&x| (-14.0..-6.0).contains(&x))
+            .count();
+        let right = samples
+            .iter()
+            .filter(|&&x| (6.0..14.0).contains(&x))
+            .count();
+        let middle = samples.iter().filter(|&&x| x.abs() < 5.0).count();
+        assert!((2_200..2_800).contains(&left), "left: {}", left);
+        assert!((7_200..7_800).contains(&right), "right: {}", right);
+        assert_eq!(middle, 0);
+    }
+
+    /// Tests that mixture sampling is reproducible under a fixed seed.
+    #[test]
+    fn test_gaussian_mixture_reproducible() {
+        let mixture = GaussianMixture::new(vec![
+            (0.5, 0.0, 1.0),
+            (0.5, 3.0, 2.0),
+        ])
+        .unwrap();
+        let mut rng1 = Random::with_seed(42);
+        let mut rng2 = Random::with_seed(42);
+        for _ in 0..100 {
+            assert_eq!(
+                mixture.sample(&mut rng1),
+                mixture.sample(&mut rng2)
+            );
+        }
+    }
+
+    /// Tests that invalid mixtures are rejected.
+    #[test]
+    fn test_gaussian_mixture_invalid() {
+        assert!(GaussianMixture::new(vec![]).is_err());
+        assert!(GaussianMixture::new(vec![(0.0, 0.0, 1.0)]).is_err());
+        assert!(GaussianMixture::new(vec![(-1.0, 0.0, 1.0)]).is_err());
+        assert!(GaussianMixture::new(vec![(1.0, 0.0, 0.0)]).is_err());
+        assert!(
+            GaussianMixture::new(vec![(1.0, 0.0, f64::NAN)]).is_err()
+        );
+        assert!(GaussianMixture::new(vec![
+            (1.0, 0.0, 1.0),
+            (0.0, 5.0, 1.0)
+        ])
+        .is_ok());
+    }
+
+    // Roll table tests
+    /// Tests that rolls follow the sizes of the table ranges.
+    #[test]
+    fn test_roll_table_frequencies() {
+        let mut rng = Random::with_seed(42);
+        let table = RollTable::new(vec![
+            (96..=100, 2),
+            (1..=60, 0),
+            (61..=95, 1),
+        ])
+        .unwrap();
+        let mut counts = [0u32; 3];
+        for _ in 0..100_000 {
+            counts[*table.roll(&mut rng)] += 1;
+        }
+        let expected = [60_000.0, 35_000.0, 5_000.0];
+        for (count, expected) in counts.iter().zip(expected.iter()) {
+            let relative =
+                (f64::from(*count) - expected).abs() / expected;
+            assert!(relative < 0.05, "{:?}", counts);
+        }
+    }
+
+    /// Tests that single-value ranges and the table edges are reachable.
+    #[test]
+    fn test_roll_table_edges() {
+        let mut rng = Random::with_seed(42);
+        let entries: Vec<_> = (1..=100).map(|i| (i..=i, i)).collect();
+        let table = RollTable::new(entries).unwrap();
+        let mut seen = [false; 101];
+        for _ in 0..5_000 {
+            seen[*table.roll(&mut rng) as usize] = true;
+        }
+        assert!(seen[1..].iter().all(|&s| s));
+    }
+
+    /// Tests that overlapping, incomplete and empty tables are rejected.
+    #[test]
+    fn test_roll_table_invalid() {
+        assert!(RollTable::new(vec![(1..=60, 'a'), (60..=100, 'b')])
+            .is_err());
+        assert!(RollTable::new(vec![(1..=50, 'a'), (52..=100, 'b')])
+            .is_err());
+        assert!(RollTable::new(vec![(2..=100, 'a')]).is_err());
+        assert!(RollTable::new(vec![(1..=99, 'a')]).is_err());
+        assert!(RollTable::new(vec![(1..=101, 'a')]).is_err());
+        assert!(RollTable::new(vec![(1..=u32::MAX, 'a')]).is_err());
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = 5..=4;
+        assert!(
+            RollTable::new(vec![(1..=100, 'a'), (empty, 'b')]).is_err()
+        );
+        assert!(RollTable::<char>::new(vec![]).is_err());
+        assert!(RollTable::new(vec![(1..=100, 'a')]).is_ok());
+    }
+
+    // AliasTable tests
+
+    /// Tests that `AliasTable` samples categories in proportion to their weights.
+    #[test]
+    fn test_alias_table_distribution() {
+        let weights = [1.0, 2.0, 3.0, 0.0, 4.0];
+        let table = AliasTable::new(&weights).unwrap();
+        assert_eq!(table.len(), 5);
+        let mut rng = Random::with_seed(7);
+        let mut counts = [0u32; 5];
+        let trials = 100_000;
+        for _ in 0..trials {
+            counts[table.sample(&mut rng)] += 1;
+        }
+        assert_eq!(counts[3], 0);
+        for (i, &w) in weights.iter().enumerate() {
+            let observed = f64::from(counts[i]) / f64::from(trials);
+            assert!((observed - w / 10.0).abs() < 0.01, "index {}", i);
+        }
+    }
+
+    /// Tests that `AliasTable::sample` rejects column draws that would bias the result.
+    #[test]
+    fn test_alias_table_column_rejection() {
+        let params =
+            vrd::mersenne_twister::MersenneTwisterParams::default();
+        let table = AliasTable::new(&[1.0, 1.0, 1.0]).unwrap();
+        // 2^32 mod 3 is 1, so `u32::MAX` is rejected and the next draw picks the column.
+        let mut rng = Random::new();
+        rng.mt[0] = Random::untemper(u32::MAX, &params);
+        rng.mt[1] = Random::untemper(4, &params);
+        rng.set_mti(0);
+        assert_eq!(table.sample(&mut rng), 1);
+    }
+
+    /// Tests that a deserialized `AliasTable` samples identically to the original.
+    #[test]
+    fn test_alias_table_serde_round_trip() {
+        let table =
+            AliasTable::new(&[0.5, 0.25, 0.125, 0.125]).unwrap();
+        let json = serde_json::to_string(&table).unwrap();
+        let restored: AliasTable = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, table);
+        let mut a = Random::with_seed(11);
+        let mut b = Random::with_seed(11);
+        for _ in 0..100 {
+            assert_eq!(table.sample(&mut a), restored.sample(&mut b));
+        }
+    }
+
+    /// Tests that `AliasTable::new` rejects invalid weights.
+    #[test]
+    fn test_alias_table_invalid_weights() {
+        assert!(AliasTable::new(&[]).is_err());
+        assert!(AliasTable::new(&[1.0, -1.0]).is_err());
+        assert!(AliasTable::new(&[0.0, 0.0]).is_err());
+        assert!(AliasTable::new(&[1.0, f64::NAN]).is_err());
+        assert!(AliasTable::new(&[1.0, f64::INFINITY]).is_err());
+    }
+
+    // PoissonSampler tests
+
+    /// Returns the sample mean and variance of `n` draws from `sampler`.
+    fn poisson_moments(
+        sampler: &PoissonSampler,
+        n: usize,
+    ) -> (f64, f64) {
+        let mut rng = Random::with_seed(42);
+        let samples: Vec<f64> =
+            (0..n).map(|_| sampler.sample(&mut rng) as f64).collect();
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        let variance =
+            samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>()
+                / (n - 1) as f64;
+        (mean, variance)
+    }
+
+    /// Tests that `PoissonSampler` has the right mean and variance for small and large means.
+    #[test]
+    fn test_poisson_sampler_moments() {
+        for lambda in [0.5, 4.0, 25.0, 1_000.0] {
+            let sampler = PoissonSampler::new(lambda).unwrap();
+            assert_eq!(sampler.uses_ptrs(), lambda >= 10.0);
+            let (mean, variance) = poisson_moments(&sampler, 100_000);
+            assert!((mean - lambda).abs() < 0.02 * lambda.max(1.0));
+            assert!((variance - lambda).abs() < 0.05 * lambda.max(1.0));
+        }
+    }
+
+    /// Tests that `PoissonSampler` matches `Random::poisson` on both sides of the switch.
+    #[test]
+    fn test_poisson_sampler_matches_method() {
+        for lambda in [0.0, 3.0, 9.5, 10.0, 60.0] {
+            let sampler = PoissonSampler::new(lambda).unwrap();
+            let mut a = Random::with_seed(9);
+            let mut b = Random::with_seed(9);
+            for _ in 0..1_000 {
+                assert_eq!(sampler.sample(&mut a), b.poisson(lambda));
+            }
+        }
+    }
+
+    /// Tests that `PoissonSampler::with_threshold` moves the algorithm switch and validates its inputs.
+    #[test]
+    fn test_poisson_sampler_threshold() {
+        let exact =
+            PoissonSampler::with_threshold(50.0, 100.0).unwrap();
+        assert!(!exact.uses_ptrs());
+        let (mean, variance) = poisson_moments(&exact, 50_000);
+        assert!((mean - 50.0).abs() < 0.5);
+        assert!((variance - 50.0).abs() < 2.5);
+        assert!(PoissonSampler::with_threshold(50.0, 20.0)
+            .unwrap()
+            .uses_ptrs());
+        assert!(PoissonSampler::with_threshold(5.0, 9.0).is_err());
+        assert!(PoissonSampler::with_threshold(5.0, 800.0).is_err());
+        assert!(PoissonSampler::new(-1.0).is_err());
+        assert!(PoissonSampler::new(f64::NAN).is_err());
+    }
+}